@@ -0,0 +1,361 @@
+//! Derive macros for `nolife`. Use them through the `derive` feature of `nolife` instead of
+//! depending on this crate directly.
+
+#![warn(clippy::pedantic)]
+
+use std::{fmt::Write, iter::Peekable};
+
+use proc_macro::{Delimiter, Spacing, Span, TokenStream, TokenTree};
+
+/// Derive `nolife::Split`, generating a `<Name>Fields` struct with disjoint references to fields
+#[proc_macro_derive(Split)]
+pub fn derive_split(input: TokenStream) -> TokenStream {
+    match Struct::parse(input) {
+        Ok(parsed) => parsed.expand_split(),
+        Err(Error { span, message }) => compile_error(span, message),
+    }
+}
+
+struct Error {
+    span: Span,
+    message: &'static str,
+}
+
+type Result<T> = std::result::Result<T, Error>;
+
+struct Struct {
+    vis: String,
+    name: String,
+    generics: Vec<Param>,
+    where_clause: String,
+    fields: Vec<Field>,
+}
+
+struct Param {
+    /// Declaration without default, e.g. `T: Clone`
+    decl: String,
+    /// Usage, e.g. `T` or `'a`
+    name: String,
+}
+
+struct Field {
+    vis: String,
+    name: String,
+    ty: String,
+}
+
+fn is_punct(token: Option<&TokenTree>, ch: char) -> bool {
+    matches!(token, Some(TokenTree::Punct(punct)) if punct.as_char() == ch)
+}
+
+fn is_ident(token: Option<&TokenTree>, name: &str) -> bool {
+    matches!(token, Some(TokenTree::Ident(ident)) if ident.to_string() == name)
+}
+
+fn to_string(tokens: &[TokenTree]) -> String {
+    tokens.iter().cloned().collect::<TokenStream>().to_string()
+}
+
+/// Skip outer attributes, returning whether any of them is `#[repr(packed)]`
+fn skip_attributes(tokens: &mut Peekable<impl Iterator<Item = TokenTree>>) -> bool {
+    let mut packed = false;
+    while is_punct(tokens.peek(), '#') {
+        tokens.next();
+        if let Some(TokenTree::Group(attr)) = tokens.next() {
+            let attr = attr.stream().to_string();
+            packed |= attr.starts_with("repr") && attr.contains("packed");
+        }
+    }
+    packed
+}
+
+fn parse_vis(tokens: &mut Peekable<impl Iterator<Item = TokenTree>>) -> String {
+    if !is_ident(tokens.peek(), "pub") {
+        return String::new();
+    }
+    let mut vis = vec![tokens.next().expect("peeked")];
+    if let Some(TokenTree::Group(group)) = tokens.peek() {
+        if group.delimiter() == Delimiter::Parenthesis {
+            vis.push(tokens.next().expect("peeked"));
+        }
+    }
+    to_string(&vis)
+}
+
+/// Split tokens by commas which are not nested in angle brackets
+fn split_commas(tokens: impl IntoIterator<Item = TokenTree>) -> Vec<Vec<TokenTree>> {
+    let mut chunks = vec![Vec::new()];
+    let mut depth = 0_usize;
+    let mut arrow = false;
+    for token in tokens {
+        if let TokenTree::Punct(punct) = &token {
+            match punct.as_char() {
+                ',' if depth == 0 => {
+                    chunks.push(Vec::new());
+                    continue;
+                }
+                '<' => depth += 1,
+                // `->` in function types is not a closing bracket
+                '>' if !arrow => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+            arrow = punct.as_char() == '-' && punct.spacing() == Spacing::Joint;
+        } else {
+            arrow = false;
+        }
+        chunks.last_mut().expect("never empty").push(token);
+    }
+    chunks.retain(|chunk| !chunk.is_empty());
+    chunks
+}
+
+impl Param {
+    fn parse(tokens: &[TokenTree]) -> Self {
+        // Defaults are only allowed in the struct declaration
+        let decl: Vec<_> = tokens
+            .iter()
+            .take_while(|token| !is_punct(Some(token), '='))
+            .cloned()
+            .collect();
+        let name = if is_punct(decl.first(), '\'') {
+            to_string(decl.get(..2).unwrap_or(&decl))
+        } else if is_ident(decl.first(), "const") {
+            to_string(decl.get(1..2).unwrap_or(&decl))
+        } else {
+            to_string(decl.get(..1).unwrap_or(&decl))
+        };
+        Self {
+            decl: to_string(&decl),
+            name,
+        }
+    }
+}
+
+impl Field {
+    fn parse(tokens: Vec<TokenTree>) -> Result<Self> {
+        let span = tokens.first().map_or_else(Span::call_site, TokenTree::span);
+        let mut tokens = tokens.into_iter().peekable();
+        skip_attributes(&mut tokens);
+        let vis = parse_vis(&mut tokens);
+        let Some(TokenTree::Ident(name)) = tokens.next() else {
+            return Err(Error {
+                span,
+                message: "expected field name",
+            });
+        };
+        if !is_punct(tokens.next().as_ref(), ':') {
+            return Err(Error {
+                span: name.span(),
+                message: "expected `:` after field name",
+            });
+        }
+        let ty: Vec<_> = tokens.collect();
+        Ok(Self {
+            vis,
+            name: name.to_string(),
+            ty: to_string(&ty),
+        })
+    }
+}
+
+impl Struct {
+    fn parse(input: TokenStream) -> Result<Self> {
+        let mut tokens = input.into_iter().peekable();
+        if skip_attributes(&mut tokens) {
+            return Err(Error {
+                span: Span::call_site(),
+                message: "`Split` can't be derived for packed structs",
+            });
+        }
+        let vis = parse_vis(&mut tokens);
+        match tokens.next() {
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "struct" => {}
+            token => {
+                return Err(Error {
+                    span: token.map_or_else(Span::call_site, |token| token.span()),
+                    message: "`Split` can only be derived for structs",
+                })
+            }
+        }
+        let Some(TokenTree::Ident(name)) = tokens.next() else {
+            return Err(Error {
+                span: Span::call_site(),
+                message: "expected struct name",
+            });
+        };
+
+        let mut generics = Vec::new();
+        if is_punct(tokens.peek(), '<') {
+            tokens.next();
+            let mut inner = Vec::new();
+            let mut depth = 1_usize;
+            let mut arrow = false;
+            for token in tokens.by_ref() {
+                if let TokenTree::Punct(punct) = &token {
+                    match punct.as_char() {
+                        '<' => depth += 1,
+                        '>' if !arrow => depth -= 1,
+                        _ => {}
+                    }
+                    arrow = punct.as_char() == '-' && punct.spacing() == Spacing::Joint;
+                } else {
+                    arrow = false;
+                }
+                if depth == 0 {
+                    break;
+                }
+                inner.push(token);
+            }
+            generics = split_commas(inner)
+                .into_iter()
+                .map(|param| Param::parse(&param))
+                .collect();
+        }
+
+        let mut where_clause = Vec::new();
+        while let Some(token) = tokens.peek() {
+            if matches!(token, TokenTree::Group(group) if group.delimiter() == Delimiter::Brace) {
+                break;
+            }
+            if matches!(token, TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis)
+                || is_punct(Some(token), ';')
+            {
+                return Err(Error {
+                    span: token.span(),
+                    message: "`Split` can only be derived for structs with named fields",
+                });
+            }
+            where_clause.push(tokens.next().expect("peeked"));
+        }
+        let Some(TokenTree::Group(body)) = tokens.next() else {
+            return Err(Error {
+                span: name.span(),
+                message: "expected struct body",
+            });
+        };
+        let fields = split_commas(body.stream())
+            .into_iter()
+            .map(Field::parse)
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            vis,
+            name: name.to_string(),
+            generics,
+            where_clause: to_string(&where_clause),
+            fields,
+        })
+    }
+
+    fn expand_split(&self) -> TokenStream {
+        let Self {
+            vis,
+            name: struct_name,
+            where_clause,
+            ..
+        } = self;
+        let fields_name = format!("{struct_name}Fields");
+        let mut decls = String::new();
+        let mut names = String::new();
+        for param in &self.generics {
+            write!(decls, "{}, ", param.decl).expect("writing to a string can't fail");
+            write!(names, "{}, ", param.name).expect("writing to a string can't fail");
+        }
+        let brand = "__NolifeBrand";
+        let is_brand = "::nolife::brand::IsBrand";
+
+        let mut field_decls = String::new();
+        let mut field_inits = String::new();
+        let mut field_checks = Vec::new();
+        for (index, field) in self.fields.iter().enumerate() {
+            let Field { vis, name, ty } = field;
+            write!(
+                field_decls,
+                "{vis} {name}: ::nolife::RefMut<{ty}, ::nolife::brand::Field<{brand}, {index}>>,"
+            )
+            .expect("writing to a string can't fail");
+            write!(
+                field_inits,
+                "{name}: ::nolife::Ref::new(\
+                     ::core::ptr::NonNull::new_unchecked(::core::ptr::addr_of_mut!((*raw).{name})),\
+                     ::nolife::brand::Field::new(),\
+                 ),"
+            )
+            .expect("writing to a string can't fail");
+            field_checks.push(format!(
+                "self.{name}.is_at(unsafe {{ ::core::ptr::addr_of_mut!((*raw).{name}) }})"
+            ));
+        }
+
+        if field_checks.is_empty() {
+            field_checks.push("true".to_owned());
+        }
+        let field_checks = field_checks.join(" && ");
+
+        format!(
+            r#"
+            /// Disjoint mutable references to fields of [`{struct_name}`]
+            #[allow(missing_docs)]
+            {vis} struct {fields_name}<{decls} {brand}: {is_brand}> {where_clause} {{
+                {field_decls}
+                __nolife_parent: ::core::ptr::NonNull<{struct_name}<{names}>>,
+                __nolife_brand: {brand},
+            }}
+
+            // SAFETY: fields are disjoint and have distinct brands, and the reference to the
+            // whole value can only be obtained back when all of them are consumed
+            unsafe impl<{decls}> ::nolife::Split for {struct_name}<{names}> {where_clause} {{
+                type Fields<{brand}: {is_brand}> = {fields_name}<{names} {brand}>;
+
+                unsafe fn split_fields<{brand}: {is_brand}>(
+                    ptr: ::core::ptr::NonNull<Self>,
+                    brand: {brand},
+                ) -> Self::Fields<{brand}> {{
+                    let raw = ptr.as_ptr();
+                    // SAFETY: `ptr` is exclusively borrowed by the caller, and each field gets
+                    // its own brand
+                    unsafe {{
+                        {fields_name} {{
+                            {field_inits}
+                            __nolife_parent: ptr,
+                            __nolife_brand: brand,
+                        }}
+                    }}
+                }}
+            }}
+
+            impl<{decls} {brand}: {is_brand}> {fields_name}<{names} {brand}> {where_clause} {{
+                /// Join the field references back into a reference to the whole value
+                #[must_use]
+                {vis} fn reunite(self) -> ::nolife::RefMut<{struct_name}<{names}>, {brand}> {{
+                    let raw = self.__nolife_parent.as_ptr();
+                    // Fields with `Element` brands could be swapped between values
+                    // SAFETY: the parent pointer points to a valid value
+                    ::core::assert!(
+                        {field_checks},
+                        "field references belong to different values"
+                    );
+                    // SAFETY: all field references are consumed, so the whole value is
+                    // exclusively borrowed again
+                    unsafe {{ ::nolife::Ref::new(self.__nolife_parent, self.__nolife_brand) }}
+                }}
+            }}
+            "#
+        )
+        .parse()
+        .expect("generated code is valid")
+    }
+}
+
+fn compile_error(span: Span, message: &str) -> TokenStream {
+    let error: TokenStream = format!("::core::compile_error!({message:?});")
+        .parse()
+        .expect("generated code is valid");
+    error
+        .into_iter()
+        .map(|mut token| {
+            token.set_span(span);
+            token
+        })
+        .collect()
+}
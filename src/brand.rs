@@ -1,7 +1,11 @@
 //! Internal machinery for tracking reference origins
 
-mod sealed {
-    pub trait Seal {}
+pub(crate) mod sealed {
+    pub trait Seal: Sized {
+        /// Create a brand out of nothing. Same safety requirements as
+        /// [`IsBrand::duplicate`](super::IsBrand::duplicate) apply.
+        unsafe fn conjure() -> Self;
+    }
 }
 
 /// Implemented for all `Brand<_>` types
@@ -20,6 +24,16 @@ pub trait IsBrand: sealed::Seal + Sized {
 pub mod closure;
 #[cfg(feature = "const_string_brands")]
 pub mod const_string;
+mod element;
+mod field;
+mod owning;
+mod reborrow;
+mod scope;
+pub use element::Element;
+pub use field::Field;
+pub use owning::Owning;
+pub use reborrow::Reborrow;
+pub use scope::Scope;
 
 #[cfg(not(feature = "const_string_brands"))]
 pub use closure::{brand, Brand};
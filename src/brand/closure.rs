@@ -2,7 +2,10 @@
 //!
 //! Simple, doesn't require additional nightly features, but produces bad error messages
 
-use std::marker::PhantomData;
+use std::{
+    marker::PhantomData,
+    panic::{RefUnwindSafe, UnwindSafe},
+};
 
 /// An unique type generated by the [`brand!`] macro.
 /// Guaranteed to be zero-sized.
@@ -29,13 +32,25 @@ impl<F> Brand<F> {
     }
 }
 
-impl<F> super::sealed::Seal for Brand<F> {}
+impl<F> super::sealed::Seal for Brand<F> {
+    unsafe fn conjure() -> Self {
+        Self(PhantomData)
+    }
+}
 impl<F> super::IsBrand for Brand<F> {
     unsafe fn duplicate(self) -> (Self, Self) {
         (self, Self(PhantomData))
     }
 }
 
+// The brand only uses the type of the closure and never calls it
+impl<F> UnwindSafe for Brand<F> {}
+impl<F> RefUnwindSafe for Brand<F> {}
+// SAFETY: the brand holds no data, only the type of the closure
+unsafe impl<F> Send for Brand<F> {}
+// SAFETY: same as above
+unsafe impl<F> Sync for Brand<F> {}
+
 /// Generate a new unique brand. This is safe since macro will yield a new brand every time
 #[macro_export]
 // Unfortunately, macros are not scoped properly
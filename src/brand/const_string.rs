@@ -27,7 +27,11 @@ impl<const S: &'static str> Brand<S> {
     }
 }
 
-impl<const S: &'static str> super::sealed::Seal for Brand<S> {}
+impl<const S: &'static str> super::sealed::Seal for Brand<S> {
+    unsafe fn conjure() -> Self {
+        Self(())
+    }
+}
 impl<const S: &'static str> super::IsBrand for Brand<S> {
     unsafe fn duplicate(self) -> (Self, Self) {
         (self, Self(()))
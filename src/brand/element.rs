@@ -0,0 +1,47 @@
+//! Brands of references to elements, derived from the brand of the whole collection
+
+use std::marker::PhantomData;
+
+use super::IsBrand;
+
+/// Brand of a reference to one of many disjoint elements of a collection branded with `B`.
+/// Created by [`RefMut::each_mut`](crate::Ref::each_mut).
+///
+/// Unlike other brands, there may be many mutable references with the same `Element` brand at
+/// once, so joining and reuniting such references checks at runtime that they point to the same
+/// place.
+#[repr(transparent)]
+pub struct Element<B>(PhantomData<B>)
+where
+    B: IsBrand;
+
+impl<B> Element<B>
+where
+    B: IsBrand,
+{
+    /// Create a new brand.
+    ///
+    /// # Safety
+    /// This can be used to create a duplicate brand, which has same safety implications as
+    /// [`IsBrand::duplicate`]
+    pub(crate) unsafe fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<B> super::sealed::Seal for Element<B>
+where
+    B: IsBrand,
+{
+    unsafe fn conjure() -> Self {
+        Self(PhantomData)
+    }
+}
+impl<B> IsBrand for Element<B>
+where
+    B: IsBrand,
+{
+    unsafe fn duplicate(self) -> (Self, Self) {
+        (self, Self(PhantomData))
+    }
+}
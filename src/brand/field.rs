@@ -0,0 +1,46 @@
+//! Brands of references to fields, derived from the brand of the whole value
+
+use std::marker::PhantomData;
+
+use super::IsBrand;
+
+/// Brand of a reference to the `INDEX`th field of a value branded with `B`. Created by
+/// [`RefMut::split_fields`](crate::Ref::split_fields).
+#[repr(transparent)]
+pub struct Field<B, const INDEX: usize>(PhantomData<B>)
+where
+    B: IsBrand;
+
+impl<B, const INDEX: usize> Field<B, INDEX>
+where
+    B: IsBrand,
+{
+    /// An implementation detail used by `#[derive(Split)]`. Don't use it unless you know exactly
+    /// what you're doing.
+    ///
+    /// # Safety
+    /// This can be used to create a duplicate brand, which has same safety implications as
+    /// [`IsBrand::duplicate`]
+    #[doc(hidden)]
+    #[must_use]
+    pub unsafe fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<B, const INDEX: usize> super::sealed::Seal for Field<B, INDEX>
+where
+    B: IsBrand,
+{
+    unsafe fn conjure() -> Self {
+        Self(PhantomData)
+    }
+}
+impl<B, const INDEX: usize> IsBrand for Field<B, INDEX>
+where
+    B: IsBrand,
+{
+    unsafe fn duplicate(self) -> (Self, Self) {
+        (self, Self(PhantomData))
+    }
+}
@@ -0,0 +1,72 @@
+//! Brands of references which carry their own husk
+
+use std::{
+    marker::PhantomData,
+    panic::{RefUnwindSafe, UnwindSafe},
+};
+
+use super::IsBrand;
+use crate::{owned::TrivialHusk, Husk};
+
+/// Brand of a reference created by [`borrow_owning!`](crate::borrow_owning!) from an
+/// [`Owned<T, Kind>`](crate::Owned) value, whose husk is trivial and was consumed. Such a reference
+/// can be turned back into the owned value with [`.into_owned()`](crate::Ref::into_owned).
+#[repr(transparent)]
+pub struct Owning<B, T, Kind>(PhantomData<B>, PhantomData<T>, PhantomData<fn() -> Kind>)
+where
+    B: IsBrand,
+    T: ?Sized;
+
+impl<B, T, Kind> Owning<B, T, Kind>
+where
+    B: IsBrand,
+    T: ?Sized,
+    Kind: TrivialHusk<T>,
+{
+    /// An implementation detail used by the [`borrow_owning!`](crate::borrow_owning!) macro.
+    /// Don't use it unless you know exactly what you're doing.
+    ///
+    /// # Safety
+    /// `brand` must be the duplicate of the brand of `husk`, which has same safety implications
+    /// as [`IsBrand::duplicate`]
+    #[doc(hidden)]
+    #[must_use]
+    pub unsafe fn new(brand: B, husk: Husk<T, B, Kind>) -> Self {
+        // The husk carries no information, so it's enough to know its kind
+        let ((), _) = (husk.into_inner(), brand);
+        Self(PhantomData, PhantomData, PhantomData)
+    }
+}
+
+impl<B, T, Kind> super::sealed::Seal for Owning<B, T, Kind>
+where
+    B: IsBrand,
+    T: ?Sized,
+{
+    unsafe fn conjure() -> Self {
+        Self(PhantomData, PhantomData, PhantomData)
+    }
+}
+impl<B, T, Kind> IsBrand for Owning<B, T, Kind>
+where
+    B: IsBrand,
+    T: ?Sized,
+{
+    unsafe fn duplicate(self) -> (Self, Self) {
+        (self, Self(PhantomData, PhantomData, PhantomData))
+    }
+}
+
+// The brand holds no `T`, it only remembers its type
+impl<B, T, Kind> UnwindSafe for Owning<B, T, Kind>
+where
+    B: IsBrand + UnwindSafe,
+    T: ?Sized,
+{
+}
+impl<B, T, Kind> RefUnwindSafe for Owning<B, T, Kind>
+where
+    B: IsBrand + RefUnwindSafe,
+    T: ?Sized,
+{
+}
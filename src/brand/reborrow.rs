@@ -0,0 +1,50 @@
+//! Brands of temporary references, which borrow from a reference with another brand
+
+use std::{
+    marker::PhantomData,
+    panic::{RefUnwindSafe, UnwindSafe},
+};
+
+use super::IsBrand;
+
+/// Brand of a reference created by [`RefMut::reborrow`](crate::Ref::reborrow), which borrows
+/// from a reference branded with `B` for `'a`
+#[repr(transparent)]
+pub struct Reborrow<'a, B>(PhantomData<&'a mut B>)
+where
+    B: IsBrand;
+
+impl<B> Reborrow<'_, B>
+where
+    B: IsBrand,
+{
+    /// Create a new brand.
+    ///
+    /// # Safety
+    /// This can be used to create a duplicate brand, which has same safety implications as
+    /// [`IsBrand::duplicate`]
+    pub(crate) const unsafe fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<B> super::sealed::Seal for Reborrow<'_, B>
+where
+    B: IsBrand,
+{
+    unsafe fn conjure() -> Self {
+        Self(PhantomData)
+    }
+}
+impl<B> IsBrand for Reborrow<'_, B>
+where
+    B: IsBrand,
+{
+    unsafe fn duplicate(self) -> (Self, Self) {
+        (self, Self(PhantomData))
+    }
+}
+
+// The mutable borrow is only a marker of the lifetime, the brand itself holds no data
+impl<B> UnwindSafe for Reborrow<'_, B> where B: IsBrand {}
+impl<B> RefUnwindSafe for Reborrow<'_, B> where B: IsBrand {}
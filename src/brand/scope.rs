@@ -0,0 +1,43 @@
+//! Brands which can't escape a closure
+
+use std::marker::PhantomData;
+
+use super::IsBrand;
+
+/// Brand of handles created by [`RefMut::with_frozen`](crate::Ref::with_frozen), which only live
+/// for `'scope`. The lifetime is invariant, so handles can't be stored outside of the closure.
+#[repr(transparent)]
+pub struct Scope<'scope, B>(PhantomData<B>, PhantomData<fn(&'scope ()) -> &'scope ()>)
+where
+    B: IsBrand;
+
+impl<B> Scope<'_, B>
+where
+    B: IsBrand,
+{
+    /// Create a new brand.
+    ///
+    /// # Safety
+    /// This can be used to create a duplicate brand, which has same safety implications as
+    /// [`IsBrand::duplicate`]
+    pub(crate) unsafe fn new() -> Self {
+        Self(PhantomData, PhantomData)
+    }
+}
+
+impl<B> super::sealed::Seal for Scope<'_, B>
+where
+    B: IsBrand,
+{
+    unsafe fn conjure() -> Self {
+        Self(PhantomData, PhantomData)
+    }
+}
+impl<B> IsBrand for Scope<'_, B>
+where
+    B: IsBrand,
+{
+    unsafe fn duplicate(self) -> (Self, Self) {
+        (self, Self(PhantomData, PhantomData))
+    }
+}
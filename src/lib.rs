@@ -31,6 +31,16 @@
 //! let mut reference = ref1.join(ref2);
 //! *reference += 1;
 //! ```
+//! Mutable references also have the accessors of [`RefCell`](std::cell::RefCell):
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! # let (_husk, mut reference) = borrow!(heap!(0));
+//! reference.set(1);
+//! assert_eq!(reference.replace(2), 1);
+//! assert_eq!(reference.take(), 2);
+//! assert_eq!(*reference, 0);
+//! ```
 //! And then reconstruct an owned value:
 //! ```
 //! # #![feature(generic_const_exprs)]
@@ -63,11 +73,17 @@
 //! let (husk2, ref2) = borrow!(heap!(0));
 //! ref2.reconstruct(husk1);
 //! ```
-   
+
 #![allow(incomplete_features, dead_code, unused_unsafe)]
 #![warn(clippy::pedantic)]
 #![feature(generic_const_exprs)]
 #![cfg_attr(feature = "const_string_brands", feature(adt_const_params))]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+#![cfg_attr(feature = "unsize", feature(coerce_unsized, unsize))]
+#![cfg_attr(
+    feature = "fn_traits",
+    feature(fn_traits, tuple_trait, unboxed_closures)
+)]
 // lint me harder
 #![forbid(non_ascii_idents)]
 #![deny(keyword_idents)]
@@ -115,10 +131,37 @@
 #![warn(clippy::useless_let_if_seq)]
 #![allow(clippy::missing_errors_doc)]
 
+// Only used by the integration tests
+#[cfg(test)]
+use {rayon as _, serde as _, serde_json as _, tokio as _};
+
 pub mod brand;
 
 mod owned;
-pub use owned::{Heap, Owned, OwnershipKind, Husk};
+pub use owned::{
+    AllocError, ArcKind, Arena, ArenaAlloc, ArenaBox, ArenaHusk, ArenaKind, ClaimOnce, CloneInner,
+    GroupElement, GroupHusk, GroupKind, Heap, Husk, ManualSlot, MoveOut, MutexHusk, MutexKind,
+    Owned, OwnershipKind, PairBox, PairHusk, PairKind, Pinned, PinnedHeap, Pool, PoolBox, PoolHusk,
+    PoolKind, RcKind, Stack, StackBox, StackHusk, StaticKind, StaticPool, StaticPoolBox,
+    StaticPoolHusk, StaticPoolKind, StringHusk, StringKind, TrivialHusk, TryMoveOut, VecHusk,
+    VecKind,
+};
+#[cfg(feature = "allocator_api")]
+pub use owned::{AllocHusk, HeapIn};
+#[cfg(feature = "memmap")]
+pub use owned::{MmapHusk, MmapKind, ReadOnlyBytes, ReadOnlyMmapKind};
+#[doc(hidden)]
+pub use owned::{PinnedField, StackSlot};
 
 mod reference;
-pub use reference::{Ref, RefMut};
+#[cfg(feature = "bytemuck")]
+pub use reference::Bytes;
+pub use reference::{
+    ptr_eq, BrandMismatch, CanJoin, CanSplit, Chunks, Collector, DynRef, ErasedHusk, ErasedRef,
+    ErasedRefMut, Exclusive, Frozen, GetManyError, IntoIterMut, JoinError, JoinErrorKind,
+    Projected, Ref, RefBundle, RefDescription, RefMut, Remainder, Reunite, SameBrand, SharedRef,
+    Split,
+};
+
+#[cfg(feature = "derive")]
+pub use nolife_derive::Split;
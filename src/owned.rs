@@ -1,63 +1,247 @@
-use std::ptr::NonNull;
+use std::{
+    alloc::{dealloc, Layout},
+    any::type_name,
+    borrow::{Borrow, BorrowMut},
+    fmt,
+    mem::{ManuallyDrop, MaybeUninit},
+    ops::{Deref, DerefMut},
+    ptr::{self, NonNull},
+};
 
 use crate::brand::IsBrand;
 
-mod seal {
-    pub trait Sealed {}
-}
+mod any;
+mod arc;
+mod arena;
+mod claim;
+mod fallible;
+mod group;
+#[cfg(feature = "allocator_api")]
+mod heap_in;
+mod in_place;
+mod manual;
+#[cfg(feature = "memmap")]
+mod mmap;
+mod mutex;
+mod pair;
+mod pinned;
+mod pool;
+mod rc;
+#[cfg(feature = "serde")]
+mod serialize;
+mod slice;
+mod stack;
+mod static_mut;
+mod static_pool;
+mod string;
+mod string_buf;
+mod vec;
+#[cfg(feature = "bytemuck")]
+mod zeroed;
+pub use arc::ArcKind;
+pub use arena::{Arena, ArenaAlloc, ArenaBox, ArenaHusk, ArenaKind};
+pub use claim::ClaimOnce;
+pub use fallible::AllocError;
+pub use group::{GroupElement, GroupHusk, GroupKind};
+#[cfg(feature = "allocator_api")]
+pub use heap_in::{AllocHusk, HeapIn};
+pub use manual::ManualSlot;
+#[cfg(feature = "memmap")]
+pub use mmap::{MmapHusk, MmapKind, ReadOnlyBytes, ReadOnlyMmapKind};
+pub use mutex::{MutexHusk, MutexKind};
+pub use pair::{PairBox, PairHusk, PairKind};
+pub use pinned::{Pinned, PinnedField, PinnedHeap};
+pub use pool::{Pool, PoolBox, PoolHusk, PoolKind};
+pub use rc::RcKind;
+pub use stack::{Stack, StackBox, StackHusk, StackSlot};
+pub use static_mut::StaticKind;
+pub use static_pool::{StaticPool, StaticPoolBox, StaticPoolHusk, StaticPoolKind};
+pub use string_buf::{StringHusk, StringKind};
+pub use vec::{VecHusk, VecKind};
 
-/// Only implemented for [`Heap`] for now. I'm still searching for a nice enough hack to support
-/// stack ownership
-pub trait OwnershipKind<T>: seal::Sealed {
+/// Storage strategy of an [`Owned`] value, e.g. [`Heap`], [`Stack`] or [`RcKind`]
+///
+/// # Safety
+/// References don't carry a lifetime, so the implementation must guarantee that:
+/// 1. The pointer returned from [`.split()`](OwnershipKind::split) (or from a successful
+///    [`.try_split()`](OwnershipKind::try_split)) is non-dangling, properly aligned and points to
+///    an initialized `Pointee` which is valid for reads and writes.
+/// 2. Until the pointer is passed back to [`.join()`](OwnershipKind::join), nothing except the
+///    references accesses the value. In particular, the husk must not access it, and neither can
+///    anything else which may share the storage (e.g. other [`Rc`](std::rc::Rc)s).
+/// 3. If the husk is dropped instead of being joined, the value is never deallocated or dropped,
+///    i.e. it's leaked, or the process is aborted before the storage is reused.
+/// 4. `Inner` returned from `.join()` owns the value again, like before the split.
+///
+/// `Owned` and its husk are thread-safe exactly when `Inner` and `Husk` are, so these types must
+/// not be [`Send`] or [`Sync`] unless sending or sharing them is sound.
+pub unsafe trait OwnershipKind<T>
+where
+    T: ?Sized,
+{
+    /// Type of the value as seen through references. Usually `T`, but kinds may restrict what
+    /// references can do by exposing an opaque type instead
+    type Pointee: ?Sized;
     type Husk;
     type Inner;
 
-    fn split(val: Self::Inner) -> (Self::Husk, NonNull<T>);
+    fn split(val: Self::Inner) -> (Self::Husk, NonNull<Self::Pointee>);
+    /// Like [`.split()`](OwnershipKind::split), but gives the value back instead of panicking for
+    /// kinds which can only be split under some runtime condition
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    fn try_split(val: Self::Inner) -> Result<(Self::Husk, NonNull<Self::Pointee>), Self::Inner> {
+        Ok(Self::split(val))
+    }
     /// # Safety
     /// `ptr` must be obtained from [`.split()`](OwnershipKind::split)
     /// and you must transfer full ownership to this method.
     ///
     /// No references are allowed to exist at this point and until next `.split()`.
-    unsafe fn join(husk: Self::Husk, ptr: NonNull<T>) -> Self::Inner;
+    unsafe fn join(husk: Self::Husk, ptr: NonNull<Self::Pointee>) -> Self::Inner;
+    /// Release the value when the [`Owned`] is dropped. Kinds whose `Inner` doesn't own the value
+    /// by itself (e.g. an index into some external storage) can override this to release it
+    #[inline]
+    fn drop_inner(val: Self::Inner) {
+        drop(val);
+    }
+    /// Drop the value and release its storage, like dropping the reconstructed [`Owned`]
+    ///
+    /// # Safety
+    /// Same as for [`.join()`](OwnershipKind::join)
+    #[inline]
+    unsafe fn discard(husk: Self::Husk, ptr: NonNull<Self::Pointee>) {
+        // SAFETY: guaranteed by the caller
+        Self::drop_inner(unsafe { Self::join(husk, ptr) });
+    }
+}
 
+/// Ownership kinds which can always give up the contained value
+pub trait MoveOut<T>: OwnershipKind<T> {
     fn move_out(val: Self::Inner) -> T;
+
+    /// Move the value out of the husk and the pointer obtained from
+    /// [`.split()`](OwnershipKind::split), releasing the storage without reconstructing the inner
+    /// value first
+    ///
+    /// # Safety
+    /// Same as for [`OwnershipKind::join()`]
+    #[inline]
+    unsafe fn take_value(husk: Self::Husk, ptr: NonNull<Self::Pointee>) -> T {
+        // SAFETY: guaranteed by the caller
+        Self::move_out(unsafe { Self::join(husk, ptr) })
+    }
+}
+
+/// Ownership kinds which can give up the contained value only under some runtime condition
+pub trait TryMoveOut<T>: OwnershipKind<T> {
+    fn try_move_out(val: Self::Inner) -> Result<T, Self::Inner>;
+}
+
+/// Ownership kinds which can make a deep copy of the value in new storage, which makes [`Owned`]
+/// values of these kinds [`Clone`]
+pub trait CloneInner<T>: OwnershipKind<T>
+where
+    T: ?Sized,
+{
+    fn clone_inner(val: &Self::Inner) -> Self::Inner;
+
+    /// Overwrite `dest` with a copy of `source`, reusing the storage of `dest` if possible
+    #[inline]
+    fn clone_inner_from(dest: &mut Self::Inner, source: &Self::Inner) {
+        *dest = Self::clone_inner(source);
+    }
+}
+
+/// Ownership kinds whose husk carries no information, so references borrowed with
+/// [`borrow_owning!`](crate::borrow_owning!) can be turned back into owned values by themselves
+pub trait TrivialHusk<T: ?Sized>: OwnershipKind<T, Husk = ()> {}
+
+impl<T, Kind> TrivialHusk<T> for Kind
+where
+    T: ?Sized,
+    Kind: OwnershipKind<T, Husk = ()>,
+{
 }
 
 /// Heap-allocated ownership kind
 pub struct Heap;
 
-impl seal::Sealed for Heap {}
-impl<T> OwnershipKind<T> for Heap {
+// SAFETY: the pointer owns the allocation until it's passed back to `Box::from_raw`, and a dropped
+// husk leaks the value
+unsafe impl<T> OwnershipKind<T> for Heap
+where
+    T: ?Sized,
+{
+    type Pointee = T;
     type Husk = ();
     type Inner = Box<T>;
 
-    fn split(val: Self::Inner) -> (Self::Husk, NonNull<T>) {
+    #[inline]
+    fn split(val: Self::Inner) -> (Self::Husk, NonNull<Self::Pointee>) {
         // SAFETY: Box<T> is guaranteed not to be null
         let ptr = unsafe { NonNull::new_unchecked(Box::into_raw(val)) };
         ((), ptr)
     }
 
-    unsafe fn join(_husk: Self::Husk, ptr: NonNull<T>) -> Self::Inner {
+    #[inline]
+    unsafe fn join(_husk: Self::Husk, ptr: NonNull<Self::Pointee>) -> Self::Inner {
         // SAFETY: pointer was obtained from `.split()` and we are the only owner
         unsafe { Box::from_raw(ptr.as_ptr()) }
     }
+}
 
+impl<T> MoveOut<T> for Heap {
+    #[inline]
     fn move_out(val: Self::Inner) -> T {
         *val
     }
+
+    #[inline]
+    unsafe fn take_value(_husk: Self::Husk, ptr: NonNull<Self::Pointee>) -> T {
+        // SAFETY: pointer was obtained from `.split()` and we are the only owner, so the value is
+        // initialized and is never used again
+        let value = unsafe { ptr::read(ptr.as_ptr()) };
+        let layout = Layout::new::<T>();
+        // `Box` doesn't allocate zero-sized values
+        if layout.size() != 0 {
+            // SAFETY: the allocation was made by `Box` with the global allocator and this layout
+            unsafe { dealloc(ptr.as_ptr().cast(), layout) };
+        }
+        value
+    }
+}
+
+impl<T> CloneInner<T> for Heap
+where
+    T: ?Sized,
+    Box<T>: Clone,
+{
+    #[inline]
+    fn clone_inner(val: &Self::Inner) -> Self::Inner {
+        val.clone()
+    }
+
+    #[inline]
+    fn clone_inner_from(dest: &mut Self::Inner, source: &Self::Inner) {
+        dest.clone_from(source);
+    }
 }
 
 /// Struct representing ownership and the only reference of a value
 pub struct Owned<T, Kind>
 where
+    T: ?Sized,
     Kind: OwnershipKind<T>,
 {
-    inner: Kind::Inner,
+    // Dropped through `OwnershipKind::drop_inner()`
+    inner: ManuallyDrop<Kind::Inner>,
 }
 
 /// Struct representing ownership of a value which is currently being borrowed
 pub struct Husk<T, B, Kind>
 where
+    T: ?Sized,
     Kind: OwnershipKind<T>,
     B: IsBrand,
 {
@@ -69,48 +253,435 @@ impl<T, Kind> Owned<T, Kind>
 where
     Kind: OwnershipKind<T>,
 {
-    pub fn into_inner(self) -> T {
-        Kind::move_out(self.inner)
+    #[inline]
+    pub fn into_inner(self) -> T
+    where
+        Kind: MoveOut<T>,
+    {
+        Kind::move_out(self.take_inner())
     }
 
+    /// Obtain the contained value if the kind allows it at the moment, giving `self` back
+    /// otherwise
+    pub fn try_into_inner(self) -> Result<T, Self>
+    where
+        Kind: TryMoveOut<T>,
+    {
+        Kind::try_move_out(self.take_inner()).map_err(|inner| Self {
+            inner: ManuallyDrop::new(inner),
+        })
+    }
+}
+
+impl<T, Kind> Owned<T, Kind>
+where
+    T: ?Sized,
+    Kind: OwnershipKind<T>,
+{
     /// Reconstruct [`Owned`] object from its `inner` pointer.
     ///
     /// # Safety
     /// `inner` must be obtained by calling [`OwnershipKind::join`] with correctly branded [`Husk`].
     /// No other references are allowed to exist at this point.
+    #[inline]
     pub unsafe fn from_inner(inner: Kind::Inner) -> Self {
-        Self { inner }
+        Self {
+            inner: ManuallyDrop::new(inner),
+        }
+    }
+
+    /// Take the inner value out without running [`OwnershipKind::drop_inner()`]
+    #[inline]
+    fn take_inner(self) -> Kind::Inner {
+        let mut this = ManuallyDrop::new(self);
+        // SAFETY: `this` is never used or dropped again
+        unsafe { ManuallyDrop::take(&mut this.inner) }
     }
 
     /// Split [`Owned`] object into [`Husk`] and pointer which can be used to construct references.
     ///
     /// # Safety
     /// Same `brand` must be used when calling this method and when constructing references.
-    pub unsafe fn split<B>(self, brand: B) -> (Husk<T, B, Kind>, NonNull<T>)
+    #[inline]
+    pub unsafe fn split<B>(self, brand: B) -> (Husk<T, B, Kind>, NonNull<Kind::Pointee>)
     where
         B: IsBrand,
     {
-        let (inner, ptr) = Kind::split(self.inner);
+        let (inner, ptr) = Kind::split(self.take_inner());
         (Husk { inner, brand }, ptr)
     }
+
+    /// Like [`.split()`](Owned::split), but gives `self` back if the kind can't be split at the
+    /// moment.
+    ///
+    /// # Safety
+    /// Same `brand` must be used when calling this method and when constructing references.
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    pub unsafe fn try_split<B>(
+        self,
+        brand: B,
+    ) -> Result<(Husk<T, B, Kind>, NonNull<Kind::Pointee>), Self>
+    where
+        B: IsBrand,
+    {
+        match Kind::try_split(self.take_inner()) {
+            Ok((inner, ptr)) => Ok((Husk { inner, brand }, ptr)),
+            Err(inner) => Err(Self {
+                inner: ManuallyDrop::new(inner),
+            }),
+        }
+    }
+
+    /// Access the value without borrowing it, when the storage gives access to it by itself, like
+    /// `Box<T>` or `Rc<T>` do. The value isn't borrowed while it's owned, so this doesn't conflict
+    /// with references obtained later:
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let mut owned = heap!(vec![1, 2]);
+    /// owned.get_mut().push(3);
+    /// assert_eq!(owned.get().len(), 3);
+    /// let (husk, mut reference) = borrow!(owned);
+    /// reference.push(4);
+    /// let owned = reference.reconstruct(husk);
+    /// assert_eq!(*owned.get(), [1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn get(&self) -> &Kind::Pointee
+    where
+        Kind::Inner: Deref<Target = Kind::Pointee>,
+    {
+        &self.inner
+    }
+
+    /// Mutably access the value without borrowing it, when the storage gives mutable access to it
+    /// by itself. It isn't available when the value may be shared, like for [`RcKind`]:
+    ///
+    /// ```compile_fail
+    /// # use nolife::*;
+    /// # use std::rc::Rc;
+    /// let mut owned = Owned::from_rc(Rc::new(5));
+    /// assert_eq!(*owned.get(), 5);
+    /// *owned.get_mut() += 1;
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self) -> &mut Kind::Pointee
+    where
+        Kind::Inner: DerefMut<Target = Kind::Pointee>,
+    {
+        &mut self.inner
+    }
+}
+
+impl<T> Owned<T, Heap> {
+    /// Allocate `val` on the heap, taking ownership of it. Same as [`heap!`](crate::heap!).
+    ///
+    /// ```
+    /// # use nolife::*;
+    /// let boxed = Owned::new(5).into_box();
+    /// assert_eq!(Owned::from_box(boxed).into_inner(), 5);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new(val: T) -> Self {
+        Self::from_box(Box::new(val))
+    }
+}
+
+impl<T> Owned<T, Heap>
+where
+    T: ?Sized,
+{
+    /// Take ownership of a boxed value, which may be unsized, like a trait object:
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// use std::io::Write;
+    ///
+    /// let owned: Owned<dyn Write, Heap> = Owned::from_box(Box::new(Vec::new()));
+    /// let (husk, mut reference) = borrow!(owned);
+    /// reference.write_all(b"hello").unwrap();
+    /// let [ref1, ref2] = reference.split();
+    /// let mut reference = ref1.join(ref2);
+    /// reference.flush().unwrap();
+    /// // Unsized values can't be moved out, but can be boxed again
+    /// let boxed: Box<dyn Write> = reference.reconstruct(husk).into_box();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_box(val: Box<T>) -> Self {
+        // SAFETY: it's always safe to create a heap-allocated owned value
+        unsafe { Self::from_inner(val) }
+    }
+
+    /// Obtain ownership over the contained box
+    #[inline]
+    #[must_use]
+    pub fn into_box(self) -> Box<T> {
+        self.take_inner()
+    }
+}
+
+/// ```
+/// # use nolife::*;
+/// fn shout(text: &mut impl AsMut<String>) {
+///     text.as_mut().make_ascii_uppercase();
+/// }
+///
+/// let mut owned = heap!(String::from("hello"));
+/// shout(&mut owned);
+/// assert_eq!(AsRef::<String>::as_ref(&owned), "HELLO");
+/// ```
+impl<T> AsRef<T> for Owned<T, Heap>
+where
+    T: ?Sized,
+{
+    fn as_ref(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<T> AsMut<T> for Owned<T, Heap>
+where
+    T: ?Sized,
+{
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+}
+
+impl<T> Borrow<T> for Owned<T, Heap>
+where
+    T: ?Sized,
+{
+    fn borrow(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<T> BorrowMut<T> for Owned<T, Heap>
+where
+    T: ?Sized,
+{
+    fn borrow_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+}
+
+/// Formats the value like `Box<T>` does. The alternate form also shows the ownership kind:
+///
+/// ```
+/// # use nolife::*;
+/// let owned = heap!(vec![1]);
+/// assert_eq!(format!("{owned:?}"), "[1]");
+/// assert_eq!(
+///     format!("{owned:#?}"),
+///     "Owned {\n    kind: Heap,\n    value: [\n        1,\n    ],\n}",
+/// );
+/// ```
+impl<T, Kind> fmt::Debug for Owned<T, Kind>
+where
+    T: ?Sized,
+    Kind: OwnershipKind<T>,
+    Kind::Inner: Deref<Target = Kind::Pointee>,
+    Kind::Pointee: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.debug_struct("Owned")
+                .field("kind", &format_args!("{}", short_type_name::<Kind>()))
+                .field("value", &self.get())
+                .finish()
+        } else {
+            fmt::Debug::fmt(self.get(), f)
+        }
+    }
+}
+
+/// ```
+/// # use nolife::*;
+/// assert_eq!(format!("{}", heap!(5)), "5");
+/// assert_eq!(heap_str!("hello").to_string(), "hello");
+/// ```
+impl<T, Kind> fmt::Display for Owned<T, Kind>
+where
+    T: ?Sized,
+    Kind: OwnershipKind<T>,
+    Kind::Inner: Deref<Target = Kind::Pointee>,
+    Kind::Pointee: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.get(), f)
+    }
+}
+
+/// Name of a type without the path to it, e.g. `HeapIn<alloc::alloc::Global>`
+fn short_type_name<T>() -> &'static str
+where
+    T: ?Sized,
+{
+    let name = type_name::<T>();
+    let path = name.split('<').next().unwrap_or(name);
+    path.rfind("::")
+        .and_then(|index| name.get(index + 2..))
+        .unwrap_or(name)
+}
+
+/// ```
+/// # use nolife::*;
+/// #[derive(Default)]
+/// struct Config {
+///     verbose: bool,
+/// }
+///
+/// let owned: Owned<_, Heap> = Box::new(Config::default()).into();
+/// let boxed: Box<Config> = owned.into_box();
+/// assert!(!boxed.verbose);
+///
+/// let owned: Owned<u32, Heap> = 5.into();
+/// assert_eq!(owned.into_inner(), 5);
+/// ```
+impl<T> From<Box<T>> for Owned<T, Heap>
+where
+    T: ?Sized,
+{
+    #[inline]
+    fn from(val: Box<T>) -> Self {
+        Self::from_box(val)
+    }
+}
+
+// `From<Owned<T, Heap>> for Box<T>` is forbidden by orphan rules, since `Box` is fundamental, and
+// `Into<Box<T>>` conflicts with the blanket impl, so `.into_box()` has to be used instead
+
+impl<T> From<T> for Owned<T, Heap> {
+    #[inline]
+    fn from(val: T) -> Self {
+        Self::new(val)
+    }
+}
+
+/// Makes a deep copy of the value, like `Box<T>` does
+///
+/// ```
+/// # use nolife::*;
+/// let original = heap!(vec![1, 2]);
+/// let mut copy = original.clone();
+/// copy.get_mut().push(3);
+/// assert_eq!(*original.get(), [1, 2]);
+///
+/// // `.clone_from()` reuses the allocation of the destination
+/// let address = copy.get() as *const Vec<i32>;
+/// copy.clone_from(&original);
+/// assert_eq!(copy.get() as *const Vec<i32>, address);
+/// assert_eq!(copy.into_inner(), [1, 2]);
+///
+/// // Unsized values can be cloned too
+/// let text = heap_str!("hello");
+/// assert_eq!(text.clone().into_string(), "hello");
+/// ```
+impl<T, Kind> Clone for Owned<T, Kind>
+where
+    T: ?Sized,
+    Kind: CloneInner<T>,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        // SAFETY: the copy owns its own storage, which was never split
+        unsafe { Self::from_inner(Kind::clone_inner(&self.inner)) }
+    }
+
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        Kind::clone_inner_from(&mut self.inner, &source.inner);
+    }
+}
+
+impl<T, Kind> Drop for Owned<T, Kind>
+where
+    T: ?Sized,
+    Kind: OwnershipKind<T>,
+{
+    fn drop(&mut self) {
+        // SAFETY: the inner value is never used again
+        Kind::drop_inner(unsafe { ManuallyDrop::take(&mut self.inner) });
+    }
 }
 
 impl<T, B, Kind> Husk<T, B, Kind>
 where
+    T: ?Sized,
     B: IsBrand,
     Kind: OwnershipKind<T>,
 {
     /// Forget brand information, leaving just unbranded husk
+    #[inline]
     pub fn into_inner(self) -> Kind::Husk {
         self.inner
     }
 }
 
+impl<T, B> Husk<MaybeUninit<T>, B, Heap>
+where
+    B: IsBrand,
+{
+    /// Convert the husk of an uninitialized value, so it could be reconstructed with a reference
+    /// obtained from [`RefMut::write`](crate::Ref::write) or
+    /// [`RefMut::assume_init`](crate::Ref::assume_init)
+    #[must_use]
+    pub fn into_init(self) -> Husk<T, B, Heap> {
+        Husk {
+            inner: self.inner,
+            brand: self.brand,
+        }
+    }
+}
+
 /// Create a new [`Owned`] value on the heap
 #[macro_export]
 macro_rules! heap {
     ($val:expr) => {
-        // SAFETY: it's always safe to create a heap-allocated owned value
-        unsafe { $crate::Owned::<_, $crate::Heap>::from_inner(::std::boxed::Box::new($val)) }
+        $crate::Owned::<_, $crate::Heap>::new($val)
+    };
+}
+
+/// Create a new [`Owned`] trait object on the heap, as in `heap_dyn!(dyn Write, Vec::new())`
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// use std::fmt::Display;
+///
+/// let (husk, reference) = borrow!(heap_dyn!(dyn Display, 5));
+/// assert_eq!(reference.to_string(), "5");
+/// let owned: Owned<dyn Display, Heap> = reference.reconstruct(husk);
+/// ```
+#[macro_export]
+macro_rules! heap_dyn {
+    ($ty:ty, $val:expr) => {
+        $crate::Owned::<$ty, $crate::Heap>::from_box(
+            ::std::boxed::Box::new($val) as ::std::boxed::Box<$ty>
+        )
+    };
+}
+
+/// Create a new [`Owned`] uninitialized value on the heap. The type can be given explicitly as in
+/// `heap_uninit!(u32)`, or inferred.
+///
+/// The value can be initialized in place with [`RefMut::write`](crate::Ref::write).
+#[macro_export]
+macro_rules! heap_uninit {
+    () => {
+        $crate::heap_uninit!(_)
+    };
+    ($ty:ty) => {
+        $crate::Owned::<::core::mem::MaybeUninit<$ty>, $crate::Heap>::from_box(::std::boxed::Box::<
+            $ty,
+        >::new_uninit())
     };
 }
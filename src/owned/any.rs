@@ -0,0 +1,45 @@
+//! Downcasting owned trait objects
+//!
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! use std::any::Any;
+//!
+//! let plugins: Vec<Owned<dyn Any, Heap>> = vec![
+//!     heap_dyn!(dyn Any, 5_u32),
+//!     heap_dyn!(dyn Any, String::from("name")),
+//!     heap_dyn!(dyn Any, vec![1.0_f64]),
+//! ];
+//! let [number, name, weights] = plugins.try_into().ok().unwrap();
+//!
+//! let (husk, mut reference) = borrow!(name);
+//! assert!(reference.downcast_ref::<u32>().is_none());
+//! reference.downcast_mut::<String>().unwrap().push('!');
+//! let name = reference.reconstruct(husk);
+//!
+//! assert_eq!(number.downcast::<u32>().ok().unwrap().into_inner(), 5);
+//! assert_eq!(name.downcast::<String>().ok().unwrap().into_inner(), "name!");
+//! // A failed downcast gives the value back
+//! let weights = weights.downcast::<Vec<f32>>().err().unwrap();
+//! assert_eq!(weights.downcast::<Vec<f64>>().ok().unwrap().into_inner(), [1.0]);
+//! ```
+
+use std::any::Any;
+
+use super::{Heap, Owned};
+
+impl Owned<dyn Any, Heap> {
+    /// Attempt to downcast the value to a concrete type, keeping the allocation
+    ///
+    /// # Errors
+    /// Gives the value back if it's not a `T`.
+    pub fn downcast<T>(self) -> Result<Owned<T, Heap>, Self>
+    where
+        T: Any,
+    {
+        self.into_box()
+            .downcast()
+            .map(Owned::from_box)
+            .map_err(Owned::from_box)
+    }
+}
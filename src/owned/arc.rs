@@ -0,0 +1,124 @@
+//! Ownership of values behind an [`Arc`]
+//!
+//! Works like [`RcKind`](super::RcKind): the [`Owned`] value may share its allocation with other
+//! [`Arc`]s, but can only be borrowed or moved out of while it's the only one. The husk doesn't
+//! hold on to the value, so it can be parked on another thread while the value is borrowed:
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! # use std::{sync::Arc, thread};
+//! let owned = Owned::from_arc(Arc::new(vec![1, 2, 3]));
+//! let (husk, mut reference) = borrow!(owned);
+//! let supervisor = thread::spawn(move || husk);
+//! reference.push(4);
+//! let husk = supervisor.join().unwrap();
+//! let owned = reference.reconstruct(husk);
+//! assert_eq!(owned.try_into_inner().ok(), Some(vec![1, 2, 3, 4]));
+//! ```
+
+use std::{ptr::NonNull, sync::Arc};
+
+use super::{Heap, Owned, OwnershipKind, TryMoveOut};
+
+/// Atomically reference-counted ownership kind, which can only be split and moved out of while the
+/// [`Arc`] is unique
+pub struct ArcKind;
+
+// SAFETY: splitting requires the `Arc` to be unique, and `Arc::into_raw` keeps the allocation alive
+// until `Arc::from_raw`
+unsafe impl<T> OwnershipKind<T> for ArcKind
+where
+    T: ?Sized,
+{
+    type Pointee = T;
+    type Husk = ();
+    type Inner = Arc<T>;
+
+    /// # Panics
+    /// Panics if there are other [`Arc`]s or [`Weak`](std::sync::Weak)s pointing to the same
+    /// allocation. Use [`try_borrow!`](crate::try_borrow!) to handle that case.
+    fn split(mut val: Self::Inner) -> (Self::Husk, NonNull<Self::Pointee>) {
+        // Weak pointers also count, since they could be upgraded while we're borrowed
+        assert!(
+            Arc::get_mut(&mut val).is_some(),
+            "tried to split a shared `Arc`"
+        );
+        // SAFETY: `Arc::into_raw` never returns null
+        let ptr = unsafe { NonNull::new_unchecked(Arc::into_raw(val).cast_mut()) };
+        ((), ptr)
+    }
+
+    fn try_split(
+        mut val: Self::Inner,
+    ) -> Result<(Self::Husk, NonNull<Self::Pointee>), Self::Inner> {
+        if Arc::get_mut(&mut val).is_none() {
+            return Err(val);
+        }
+        Ok(Self::split(val))
+    }
+
+    unsafe fn join(_husk: Self::Husk, ptr: NonNull<Self::Pointee>) -> Self::Inner {
+        // SAFETY: pointer was obtained from `Arc::into_raw` in `.split()` and we are the only owner
+        unsafe { Arc::from_raw(ptr.as_ptr()) }
+    }
+}
+
+impl<T> TryMoveOut<T> for ArcKind {
+    fn try_move_out(val: Self::Inner) -> Result<T, Self::Inner> {
+        Arc::try_unwrap(val)
+    }
+}
+
+impl<T> Owned<T, ArcKind>
+where
+    T: ?Sized,
+{
+    /// Take ownership of an [`Arc`], which may still be shared
+    pub fn from_arc(arc: Arc<T>) -> Self {
+        // SAFETY: `ArcKind` checks uniqueness whenever it's split or moved out of
+        unsafe { Self::from_inner(arc) }
+    }
+
+    /// Access the underlying [`Arc`], e.g. to clone it
+    #[must_use]
+    pub fn as_arc(&self) -> &Arc<T> {
+        &self.inner
+    }
+
+    /// Give up branded ownership, returning the underlying [`Arc`]
+    #[must_use]
+    pub fn into_arc(self) -> Arc<T> {
+        self.take_inner()
+    }
+}
+
+impl<T> Owned<T, Heap>
+where
+    T: ?Sized,
+{
+    /// Give up exclusive ownership, moving the value into a new [`Arc`]
+    #[must_use]
+    pub fn into_arc(self) -> Arc<T> {
+        Arc::from(self.take_inner())
+    }
+}
+
+impl<T> Owned<T, Heap> {
+    /// Regain exclusive ownership of the value if there are no other [`Arc`]s pointing to it
+    ///
+    /// ```
+    /// # use nolife::*;
+    /// # use std::sync::Arc;
+    /// let shared = Owned::new(5).into_arc();
+    /// let other = Arc::clone(&shared);
+    /// let shared = Owned::try_from_arc(shared).err().unwrap();
+    /// drop(other);
+    /// assert_eq!(Owned::try_from_arc(shared).ok().map(Owned::into_inner), Some(5));
+    /// ```
+    ///
+    /// # Errors
+    /// Gives the [`Arc`] back if it's shared.
+    pub fn try_from_arc(arc: Arc<T>) -> Result<Self, Arc<T>> {
+        Arc::try_unwrap(arc).map(Self::new)
+    }
+}
@@ -0,0 +1,191 @@
+//! Ownership of values allocated in an arena
+//!
+//! Memory is never freed by the values themselves: dropping an arena-owned [`Owned`] value runs
+//! its destructor, and the storage is reclaimed when the arena goes away.
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! # use std::{alloc::{self, Layout}, cell::RefCell, ptr::NonNull};
+//! /// Toy arena which frees all of its allocations at once
+//! #[derive(Default)]
+//! struct Chunks(RefCell<Vec<(NonNull<u8>, Layout)>>);
+//!
+//! unsafe impl ArenaAlloc for Chunks {
+//!     fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+//!         let layout = Layout::from_size_align(layout.size().max(1), layout.align()).unwrap();
+//!         let ptr = NonNull::new(unsafe { alloc::alloc(layout) }).unwrap();
+//!         self.0.borrow_mut().push((ptr, layout));
+//!         ptr
+//!     }
+//! }
+//!
+//! impl Drop for Chunks {
+//!     fn drop(&mut self) {
+//!         for (ptr, layout) in self.0.get_mut().drain(..) {
+//!             unsafe { alloc::dealloc(ptr.as_ptr(), layout) };
+//!         }
+//!     }
+//! }
+//!
+//! let arena = Arena::new(Chunks::default());
+//! let (husk, mut reference) = borrow!(arena.alloc(vec![1, 2]));
+//! reference.push(3);
+//! let [ref1, ref2] = reference.split();
+//! let owned = ref1.join(ref2).reconstruct(husk);
+//! assert_eq!(owned.into_inner(), [1, 2, 3]);
+//! ```
+//!
+//! References don't carry a lifetime, so the arena aborts the process if it's dropped while any of
+//! its values are still borrowed.
+//!
+//! Joining a reference with the husk of another value panics, so that one arena can't account
+//! for a value borrowed from another one:
+//! ```should_panic
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! # use std::{alloc::{self, Layout}, ptr::NonNull};
+//! # struct Leak;
+//! # unsafe impl ArenaAlloc for Leak {
+//! #     fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+//! #         let layout = Layout::from_size_align(layout.size().max(1), layout.align()).unwrap();
+//! #         NonNull::new(unsafe { alloc::alloc(layout) }).unwrap()
+//! #     }
+//! # }
+//! let (arena1, arena2) = (Arena::new(Leak), Arena::new(Leak));
+//! let mut borrowed = Vec::new();
+//! for arena in [&arena1, &arena2] {
+//!     borrowed.push(borrow!(arena.alloc(0)));
+//! }
+//! let (husk1, _ref1) = borrowed.remove(0);
+//! let (_husk2, ref2) = borrowed.remove(0);
+//! // Panics: both husks have the same brand, but the first one belongs to another value
+//! ref2.reconstruct(husk1);
+//! ```
+
+use std::{
+    alloc::Layout,
+    cell::Cell,
+    marker::PhantomData,
+    mem, process,
+    ptr::{self, NonNull},
+};
+
+use super::{MoveOut, Owned, OwnershipKind};
+
+/// Memory source for an [`Arena`]
+///
+/// # Safety
+/// Memory returned by [`.alloc_layout()`](ArenaAlloc::alloc_layout) must fit `layout`, must not
+/// be handed out again, and must stay valid until `self` is dropped.
+pub unsafe trait ArenaAlloc {
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8>;
+}
+
+/// Arena which keeps track of borrowed values
+pub struct Arena<A> {
+    alloc: A,
+    borrowed: Cell<usize>,
+}
+
+/// Ownership kind for values living in an [`Arena`]
+pub struct ArenaKind<'arena>(PhantomData<&'arena ()>);
+
+/// Value living in an [`Arena`]. Drops the value, but doesn't free the memory when dropped.
+pub struct ArenaBox<'arena, T> {
+    ptr: NonNull<T>,
+    borrowed: &'arena Cell<usize>,
+    value: PhantomData<T>,
+}
+
+/// Husk of a borrowed [`ArenaBox`]
+pub struct ArenaHusk<'arena> {
+    borrowed: &'arena Cell<usize>,
+    // Address of the value, to check that the husk is joined with its own value
+    addr: usize,
+}
+
+impl<A> Arena<A>
+where
+    A: ArenaAlloc,
+{
+    pub fn new(alloc: A) -> Self {
+        Self {
+            alloc,
+            borrowed: Cell::new(0),
+        }
+    }
+
+    /// Move `val` into the arena
+    pub fn alloc<T>(&self, val: T) -> Owned<T, ArenaKind<'_>> {
+        let ptr = self.alloc.alloc_layout(Layout::new::<T>()).cast::<T>();
+        // SAFETY: `ArenaAlloc` guarantees that memory is valid for `T` and not used by anyone else
+        unsafe { ptr.as_ptr().write(val) };
+        let inner = ArenaBox {
+            ptr,
+            borrowed: &self.borrowed,
+            value: PhantomData,
+        };
+        // SAFETY: we've just initialized this memory and nothing else owns it
+        unsafe { Owned::from_inner(inner) }
+    }
+}
+
+impl<A> Drop for Arena<A> {
+    fn drop(&mut self) {
+        if self.borrowed.get() != 0 {
+            // Some `Ref` may still point into this arena, and we can't stop it from being used
+            process::abort();
+        }
+    }
+}
+
+impl<T> Drop for ArenaBox<'_, T> {
+    fn drop(&mut self) {
+        // SAFETY: `ArenaBox` always points to an initialized value which it exclusively owns
+        unsafe { ptr::drop_in_place(self.ptr.as_ptr()) }
+    }
+}
+
+// SAFETY: arena memory is never reused, and the arena aborts if it's dropped while borrowed
+unsafe impl<'arena, T> OwnershipKind<T> for ArenaKind<'arena> {
+    type Pointee = T;
+    type Husk = ArenaHusk<'arena>;
+    type Inner = ArenaBox<'arena, T>;
+
+    fn split(val: Self::Inner) -> (Self::Husk, NonNull<Self::Pointee>) {
+        let (ptr, borrowed) = (val.ptr, val.borrowed);
+        mem::forget(val);
+        borrowed.set(borrowed.get() + 1);
+        let husk = ArenaHusk {
+            borrowed,
+            addr: ptr.as_ptr().addr(),
+        };
+        (husk, ptr)
+    }
+
+    /// # Panics
+    /// Panics if the husk belongs to another value, which can happen when husks with the same
+    /// brand are swapped.
+    unsafe fn join(husk: Self::Husk, ptr: NonNull<Self::Pointee>) -> Self::Inner {
+        assert!(
+            ptr.as_ptr().addr() == husk.addr,
+            "husk belongs to another value"
+        );
+        husk.borrowed.set(husk.borrowed.get() - 1);
+        ArenaBox {
+            ptr,
+            borrowed: husk.borrowed,
+            value: PhantomData,
+        }
+    }
+}
+
+impl<T> MoveOut<T> for ArenaKind<'_> {
+    fn move_out(val: Self::Inner) -> T {
+        let ptr = val.ptr;
+        mem::forget(val);
+        // SAFETY: `ArenaBox` points to an initialized value, and we've forgotten it, so the value
+        // won't be dropped twice. The slot is left dead.
+        unsafe { ptr::read(ptr.as_ptr()) }
+    }
+}
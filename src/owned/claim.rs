@@ -0,0 +1,96 @@
+//! Global storage which can be claimed as an [`Owned`] value by one owner at a time
+//!
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! # use std::thread;
+//! static STATE: ClaimOnce<Vec<i32>> = ClaimOnce::new();
+//!
+//! let claims: Vec<_> = (0..8)
+//!     .map(|i| thread::spawn(move || STATE.claim(vec![i]).map(Owned::into_static_mut)))
+//!     .collect();
+//! let mut claimed: Vec<_> = claims.into_iter().filter_map(|t| t.join().unwrap()).collect();
+//! assert_eq!(claimed.len(), 1);
+//!
+//! // SAFETY: this is the reference we've just claimed
+//! let owned = unsafe { Owned::from_static_mut(claimed.pop().unwrap()) };
+//! let (husk, mut reference) = borrow!(owned);
+//! reference.push(8);
+//! STATE.unclaim(reference.reconstruct(husk)).ok().unwrap();
+//!
+//! // The value is kept for the next owner
+//! let owned = STATE.claim(vec![]).unwrap();
+//! assert_eq!(owned.into_static_mut().len(), 2);
+//! assert!(STATE.claim(vec![]).is_none());
+//! ```
+
+use std::{
+    cell::UnsafeCell,
+    ptr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        OnceLock,
+    },
+};
+
+use super::{Owned, StaticKind};
+
+/// Storage for a value which can be claimed by one owner at a time, intended to be put in a
+/// `static`
+pub struct ClaimOnce<T> {
+    value: OnceLock<UnsafeCell<T>>,
+    claimed: AtomicBool,
+}
+
+// SAFETY: the value is only accessible through the single claimed `Owned`, which may be moved to
+// another thread
+unsafe impl<T> Sync for ClaimOnce<T> where T: Send {}
+
+impl<T> ClaimOnce<T>
+where
+    T: 'static,
+{
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            value: OnceLock::new(),
+            claimed: AtomicBool::new(false),
+        }
+    }
+
+    /// Claim the storage, initializing it with `init` the first time. Returns `None` if it's
+    /// already claimed, in which case `init` is dropped.
+    pub fn claim(&'static self, init: T) -> Option<Owned<T, StaticKind>> {
+        if self.claimed.swap(true, Ordering::Acquire) {
+            return None;
+        }
+        let value = self.value.get_or_init(|| UnsafeCell::new(init));
+        // SAFETY: we've just set `claimed`, so we're the only owner until `.unclaim()`
+        Some(unsafe { Owned::from_static_mut(&mut *value.get()) })
+    }
+
+    /// Give the claimed value back, so that it can be claimed again. Returns `owned` back if it
+    /// wasn't claimed from this storage.
+    pub fn unclaim(&'static self, owned: Owned<T, StaticKind>) -> Result<(), Owned<T, StaticKind>> {
+        let value = owned.into_static_mut();
+        if !self
+            .value
+            .get()
+            .is_some_and(|cell| ptr::eq(cell.get(), value))
+        {
+            // SAFETY: we're giving the reference back to its owner
+            return Err(unsafe { Owned::from_static_mut(value) });
+        }
+        self.claimed.store(false, Ordering::Release);
+        Ok(())
+    }
+}
+
+impl<T> Default for ClaimOnce<T>
+where
+    T: 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
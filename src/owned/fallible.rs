@@ -0,0 +1,90 @@
+//! Heap allocation which reports failure instead of aborting
+//!
+//! ```
+//! # use nolife::*;
+//! # use std::{alloc::{GlobalAlloc, Layout, System}, sync::atomic::{AtomicBool, Ordering}};
+//! # struct Limited;
+//! # static EXHAUSTED: AtomicBool = AtomicBool::new(false);
+//! # unsafe impl GlobalAlloc for Limited {
+//! #     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+//! #         if EXHAUSTED.load(Ordering::Relaxed) {
+//! #             return std::ptr::null_mut();
+//! #         }
+//! #         unsafe { System.alloc(layout) }
+//! #     }
+//! #     unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+//! #         unsafe { System.dealloc(ptr, layout) }
+//! #     }
+//! # }
+//! # #[global_allocator]
+//! # static GLOBAL: Limited = Limited;
+//! let owned = try_heap!(1).unwrap();
+//! assert_eq!(owned.into_inner(), 1);
+//! EXHAUSTED.store(true, Ordering::Relaxed);
+//! let result = try_heap!(2);
+//! EXHAUSTED.store(false, Ordering::Relaxed);
+//! assert_eq!(result.err(), Some(AllocError));
+//! ```
+
+#[cfg(not(feature = "allocator_api"))]
+use std::{
+    alloc::{self, Layout},
+    ptr::NonNull,
+};
+use std::{error::Error, fmt};
+
+use super::{Heap, Owned};
+
+/// The memory allocator returned an error
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AllocError;
+
+impl fmt::Display for AllocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("memory allocation failed")
+    }
+}
+
+impl Error for AllocError {}
+
+impl<T> Owned<T, Heap> {
+    /// Allocate `val` on the heap, returning an error instead of aborting if the allocation fails
+    ///
+    /// # Errors
+    /// Returns [`AllocError`] if the allocator fails to provide memory.
+    #[cfg(feature = "allocator_api")]
+    pub fn try_new(val: T) -> Result<Self, AllocError> {
+        let val = Box::try_new(val).map_err(|_| AllocError)?;
+        Ok(Self::from_box(val))
+    }
+
+    /// Allocate `val` on the heap, returning an error instead of aborting if the allocation fails
+    ///
+    /// # Errors
+    /// Returns [`AllocError`] if the allocator fails to provide memory.
+    #[cfg(not(feature = "allocator_api"))]
+    pub fn try_new(val: T) -> Result<Self, AllocError> {
+        let layout = Layout::new::<T>();
+        let ptr = if layout.size() == 0 {
+            NonNull::dangling()
+        } else {
+            // SAFETY: layout has non-zero size
+            NonNull::new(unsafe { alloc::alloc(layout) }.cast::<T>()).ok_or(AllocError)?
+        };
+        // SAFETY: `ptr` is valid for writes and was allocated by the global allocator with the
+        // layout of `T` (or is dangling for zero-sized `T`), just like `Box` does
+        let val = unsafe {
+            ptr.as_ptr().write(val);
+            Box::from_raw(ptr.as_ptr())
+        };
+        Ok(Self::from_box(val))
+    }
+}
+
+/// Like [`heap!`](crate::heap!), but evaluates to `Err(AllocError)` if the allocation fails
+#[macro_export]
+macro_rules! try_heap {
+    ($val:expr) => {
+        $crate::Owned::<_, $crate::Heap>::try_new($val)
+    };
+}
@@ -0,0 +1,135 @@
+//! Independent ownership of elements of a heap-allocated array
+//!
+//! Elements can be moved to different threads and borrowed separately, without allocating each
+//! of them:
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! let (elements, group) = heap!([1, 2, 3, 4]).split_elements();
+//! let elements = std::thread::scope(|scope| {
+//!     elements.map(|element| {
+//!         scope.spawn(move || {
+//!             let (husk, mut reference) = borrow!(element);
+//!             *reference *= 10;
+//!             reference.reconstruct(husk)
+//!         })
+//!     })
+//!     .map(|handle| handle.join().unwrap())
+//! });
+//! assert_eq!(group.reassemble(elements).into_inner(), [10, 20, 30, 40]);
+//! ```
+//!
+//! The array is only deallocated when all elements are reassembled. If the [`GroupHusk`] or some
+//! of the elements are dropped instead, the remaining elements are still dropped normally, but
+//! the allocation itself is leaked.
+
+use std::{
+    array,
+    marker::PhantomData,
+    mem::{self, ManuallyDrop},
+    ptr::NonNull,
+};
+
+use super::{Heap, MoveOut, Owned, OwnershipKind};
+
+/// Ownership kind for an element of an array split with
+/// [`.split_elements()`](Owned::split_elements)
+pub struct GroupKind;
+
+/// Element of a split array. Drops the element when dropped, but never frees the array.
+pub struct GroupElement<T> {
+    ptr: NonNull<T>,
+    marker: PhantomData<T>,
+}
+
+/// Array whose elements are owned separately. Elements can be put back with
+/// [`.reassemble()`](GroupHusk::reassemble).
+pub struct GroupHusk<T, const N: usize> {
+    base: NonNull<[T; N]>,
+}
+
+// SAFETY: `GroupElement<T>` owns a `T`
+unsafe impl<T: Send> Send for GroupElement<T> {}
+// SAFETY: `GroupElement<T>` only gives out shared access to `T` through `&self`
+unsafe impl<T: Sync> Sync for GroupElement<T> {}
+// SAFETY: the husk only owns the allocation and never accesses elements by itself
+unsafe impl<T: Send, const N: usize> Send for GroupHusk<T, N> {}
+// SAFETY: the husk has no methods taking `&self`
+unsafe impl<T, const N: usize> Sync for GroupHusk<T, N> {}
+
+impl<T> Drop for GroupElement<T> {
+    fn drop(&mut self) {
+        // SAFETY: `GroupElement` always points to an initialized value which it exclusively owns
+        unsafe { self.ptr.as_ptr().drop_in_place() }
+    }
+}
+
+// SAFETY: the element is only dropped by `GroupElement`, and the array is only freed on reassembly
+unsafe impl<T> OwnershipKind<T> for GroupKind {
+    type Pointee = T;
+    type Husk = ();
+    type Inner = GroupElement<T>;
+
+    fn split(val: Self::Inner) -> (Self::Husk, NonNull<Self::Pointee>) {
+        let val = ManuallyDrop::new(val);
+        ((), val.ptr)
+    }
+
+    unsafe fn join(_husk: Self::Husk, ptr: NonNull<Self::Pointee>) -> Self::Inner {
+        GroupElement {
+            ptr,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> MoveOut<T> for GroupKind {
+    fn move_out(val: Self::Inner) -> T {
+        let val = ManuallyDrop::new(val);
+        // SAFETY: `GroupElement` points to an initialized value, and we've forgotten it, so the
+        // value won't be dropped twice
+        unsafe { val.ptr.as_ptr().read() }
+    }
+}
+
+impl<T, const N: usize> Owned<[T; N], Heap> {
+    /// Split the array into separately owned elements and a husk which keeps the allocation
+    #[must_use]
+    pub fn split_elements(self) -> ([Owned<T, GroupKind>; N], GroupHusk<T, N>) {
+        // SAFETY: `Box::into_raw` never returns null
+        let base = unsafe { NonNull::new_unchecked(Box::into_raw(self.take_inner())) };
+        let elements = array::from_fn(|idx| {
+            // SAFETY: `idx < N`, and each element is owned by exactly one `GroupElement`
+            unsafe {
+                Owned::from_inner(GroupElement {
+                    ptr: base.cast::<T>().add(idx),
+                    marker: PhantomData,
+                })
+            }
+        });
+        (elements, GroupHusk { base })
+    }
+}
+
+impl<T, const N: usize> GroupHusk<T, N> {
+    /// Put the elements back into the array in their original order
+    ///
+    /// # Panics
+    /// Panics if some element doesn't belong to this array or isn't in its original position.
+    #[must_use]
+    pub fn reassemble(self, elements: [Owned<T, GroupKind>; N]) -> Owned<[T; N], Heap> {
+        for (idx, element) in elements.iter().enumerate() {
+            // SAFETY: `idx < N`
+            let expected = unsafe { self.base.cast::<T>().add(idx) };
+            assert!(
+                element.inner.ptr == expected,
+                "element doesn't belong to this position of the array"
+            );
+        }
+        // Elements are owned by the array again
+        mem::forget(elements);
+        // SAFETY: the pointer was obtained from `Box::into_raw`, and all elements are initialized
+        // and not owned by anything else
+        unsafe { Owned::from_inner(Box::from_raw(self.base.as_ptr())) }
+    }
+}
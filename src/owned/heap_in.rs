@@ -0,0 +1,146 @@
+//! Ownership of values allocated by a custom [`Allocator`]
+//!
+//! The allocator is carried by the husk while the value is borrowed, so the memory is freed by the
+//! same allocator it came from:
+//! ```
+//! # #![feature(allocator_api, generic_const_exprs)]
+//! # use nolife::*;
+//! # use std::{alloc::{AllocError, Allocator, Global, Layout}, cell::Cell, ptr::NonNull};
+//! #[derive(Default)]
+//! struct Counting {
+//!     allocs: Cell<usize>,
+//!     deallocs: Cell<usize>,
+//! }
+//!
+//! unsafe impl Allocator for &Counting {
+//!     fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+//!         self.allocs.set(self.allocs.get() + 1);
+//!         Global.allocate(layout)
+//!     }
+//!
+//!     unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+//!         self.deallocs.set(self.deallocs.get() + 1);
+//!         unsafe { Global.deallocate(ptr, layout) }
+//!     }
+//! }
+//!
+//! let counting = Counting::default();
+//! let (husk, mut reference) = borrow!(heap_in!(&counting, 0));
+//! *reference += 1;
+//! let [ref1, ref2] = reference.split();
+//! let owned = ref1.join(ref2).reconstruct(husk);
+//! assert_eq!(owned.into_inner(), 1);
+//! assert_eq!((counting.allocs.get(), counting.deallocs.get()), (1, 1));
+//! ```
+//!
+//! Joining a reference with the husk of another value panics instead of freeing the memory through
+//! a wrong allocator:
+//! ```should_panic
+//! # #![feature(allocator_api, generic_const_exprs)]
+//! # use nolife::*;
+//! # use std::alloc::Global;
+//! let mut borrowed = Vec::new();
+//! for value in [1, 2] {
+//!     borrowed.push(borrow!(heap_in!(Global, value)));
+//! }
+//! let (husk1, _ref1) = borrowed.remove(0);
+//! let (_husk2, ref2) = borrowed.remove(0);
+//! // Panics: both husks have the same brand, but the first one belongs to another value
+//! ref2.reconstruct(husk1);
+//! ```
+
+use std::{alloc::Allocator, marker::PhantomData, mem::ManuallyDrop, ptr::NonNull};
+
+use super::{MoveOut, Owned, OwnershipKind};
+
+/// Ownership kind for values allocated by `A`
+pub struct HeapIn<A>(PhantomData<A>);
+
+/// Husk of a borrowed [`HeapIn`] value, holding its allocator.
+///
+/// Dropping it leaks the allocator, since borrowed memory may still be in use.
+pub struct AllocHusk<A> {
+    alloc: ManuallyDrop<A>,
+    // Address of the value, to check that the husk is joined with its own value
+    addr: usize,
+}
+
+// SAFETY: the pointer owns the allocation until it's passed back to `Box::from_raw_in`, and a
+// dropped husk leaks both the value and the allocator
+unsafe impl<T, A> OwnershipKind<T> for HeapIn<A>
+where
+    T: ?Sized,
+    A: Allocator,
+{
+    type Pointee = T;
+    type Husk = AllocHusk<A>;
+    type Inner = Box<T, A>;
+
+    fn split(val: Self::Inner) -> (Self::Husk, NonNull<Self::Pointee>) {
+        let (ptr, alloc) = Box::into_raw_with_allocator(val);
+        // SAFETY: Box<T, A> is guaranteed not to be null
+        let ptr = unsafe { NonNull::new_unchecked(ptr) };
+        let husk = AllocHusk {
+            alloc: ManuallyDrop::new(alloc),
+            addr: ptr.as_ptr().addr(),
+        };
+        (husk, ptr)
+    }
+
+    /// # Panics
+    /// Panics if the husk belongs to another value, which can happen when husks with the same
+    /// brand are swapped.
+    unsafe fn join(husk: Self::Husk, ptr: NonNull<Self::Pointee>) -> Self::Inner {
+        assert!(
+            ptr.as_ptr().addr() == husk.addr,
+            "husk belongs to another value"
+        );
+        let alloc = ManuallyDrop::into_inner(husk.alloc);
+        // SAFETY: pointer was obtained from `.split()` together with this allocator and we are
+        // the only owner
+        unsafe { Box::from_raw_in(ptr.as_ptr(), alloc) }
+    }
+}
+
+impl<T, A> MoveOut<T> for HeapIn<A>
+where
+    A: Allocator,
+{
+    fn move_out(val: Self::Inner) -> T {
+        *val
+    }
+}
+
+impl<T, A> Owned<T, HeapIn<A>>
+where
+    A: Allocator,
+{
+    /// Move `val` into a new allocation made by `alloc`
+    #[inline]
+    #[must_use]
+    pub fn new_in(val: T, alloc: A) -> Self {
+        Self::from_box_in(Box::new_in(val, alloc))
+    }
+}
+
+impl<T, A> Owned<T, HeapIn<A>>
+where
+    T: ?Sized,
+    A: Allocator,
+{
+    /// Take ownership of a box allocated by a custom allocator
+    #[inline]
+    #[must_use]
+    pub fn from_box_in(val: Box<T, A>) -> Self {
+        // SAFETY: it's always safe to take ownership of a box
+        unsafe { Self::from_inner(val) }
+    }
+}
+
+/// Create a new [`Owned`] value allocated by the given allocator
+#[macro_export]
+macro_rules! heap_in {
+    ($alloc:expr, $val:expr) => {
+        $crate::Owned::<_, $crate::HeapIn<_>>::new_in($val, $alloc)
+    };
+}
@@ -0,0 +1,103 @@
+//! Heap values which are initialized directly in their allocation
+//!
+//! The closure gets an uninitialized slot and returns a reference to the initialized value,
+//! usually obtained from [`MaybeUninit::write`] or [`MaybeUninit::assume_init_mut`]:
+//! ```
+//! # use nolife::*;
+//! # use std::mem::MaybeUninit;
+//! const LEN: usize = 2 << 20;
+//! // 16 MiB, which never touches the stack
+//! let owned = heap_with!(|slot: &mut MaybeUninit<[u64; LEN]>| {
+//!     let ptr = slot.as_mut_ptr().cast::<u64>();
+//!     for idx in 0..LEN {
+//!         // SAFETY: `idx` is in bounds of the array
+//!         unsafe { ptr.add(idx).write(idx as u64) };
+//!     }
+//!     // SAFETY: all elements were initialized
+//!     unsafe { slot.assume_init_mut() }
+//! });
+//! let (husk, reference) = borrow!(owned);
+//! assert_eq!(reference[LEN - 1], LEN as u64 - 1);
+//! let owned = reference.reconstruct(husk);
+//! ```
+//!
+//! If the closure panics, the allocation is freed without dropping the value:
+//! ```
+//! # use nolife::*;
+//! struct NeverDropped;
+//! impl Drop for NeverDropped {
+//!     fn drop(&mut self) {
+//!         unreachable!();
+//!     }
+//! }
+//! let result = std::panic::catch_unwind(|| {
+//!     Owned::<NeverDropped, Heap>::new_with(|_slot| panic!("oops"))
+//! });
+//! assert!(result.is_err());
+//! ```
+
+use std::{mem::MaybeUninit, ptr};
+
+use super::{Heap, Owned};
+
+impl<T> Owned<T, Heap> {
+    /// Allocate memory for a value and initialize it in place with `init`.
+    ///
+    /// # Panics
+    /// Panics if `init` returns a reference to something other than the provided slot.
+    pub fn new_with(init: impl FnOnce(&mut MaybeUninit<T>) -> &mut T) -> Self {
+        let mut slot = Box::<T>::new_uninit();
+        let expected = slot.as_mut_ptr();
+        let initialized: *mut T = init(&mut slot);
+        assert!(
+            ptr::eq(initialized, expected),
+            "initializer returned a reference to a different value"
+        );
+        // SAFETY: there is a `&mut T` pointing into the slot, so it was initialized
+        let val = unsafe { slot.assume_init() };
+        Self::from_box(val)
+    }
+
+    /// Allocate the default value, writing it directly into the allocation. Same as
+    /// [`Owned::default`].
+    ///
+    /// ```
+    /// # use nolife::*;
+    /// #[derive(Default)]
+    /// struct Cache {
+    ///     entries: Owned<[[u64; 32]; 32], Heap>,
+    ///     hits: usize,
+    /// }
+    ///
+    /// let cache = Cache::default();
+    /// assert!(cache.entries.get().as_flattened().iter().all(|&entry| entry == 0));
+    /// assert_eq!(Owned::<u8, Heap>::new_default().into_inner(), 0);
+    /// ```
+    #[must_use]
+    pub fn new_default() -> Self
+    where
+        T: Default,
+    {
+        Self::new_with(|slot| slot.write(T::default()))
+    }
+}
+
+impl<T> Default for Owned<T, Heap>
+where
+    T: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new_default()
+    }
+}
+
+/// Create a new [`Owned`] value on the heap, initializing it in place.
+///
+/// See [`Owned::new_with`].
+#[macro_export]
+macro_rules! heap_with {
+    ($init:expr) => {
+        $crate::Owned::<_, $crate::Heap>::new_with($init)
+    };
+}
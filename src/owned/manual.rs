@@ -0,0 +1,95 @@
+//! Ownership of values in `'static` slots whose destructors are only run explicitly
+//!
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! # use std::mem::MaybeUninit;
+//! let slot: &'static mut MaybeUninit<Vec<i32>> = Box::leak(Box::new(MaybeUninit::new(vec![1])));
+//! // SAFETY: the slot is initialized
+//! let owned = unsafe { Owned::from_slot(slot) };
+//! let (husk, mut reference) = borrow!(owned);
+//! reference.push(2);
+//! let [ref1, ref2] = reference.split();
+//! let owned = ref1.join(ref2).reconstruct(husk);
+//! // Dropping `owned` would leak the vector, so we drop it explicitly
+//! let slot = owned.drop_in_place();
+//! // The slot is uninitialized now and can be reused
+//! slot.write(vec![3]);
+//! // SAFETY: we've just initialized the slot
+//! assert_eq!(unsafe { Owned::from_slot(slot) }.into_inner(), [3]);
+//! ```
+
+use std::{mem::MaybeUninit, ptr::NonNull};
+
+use super::{MoveOut, Owned, OwnershipKind};
+
+/// Ownership kind for values in `'static` [`MaybeUninit`] slots.
+///
+/// The slot is initialized while it's owned, but dropping the [`Owned`] value doesn't run the
+/// destructor: use [`.drop_in_place()`](Owned::drop_in_place) for that, or give the value back to
+/// the slot with [`.forget_into_slot()`](Owned::forget_into_slot).
+pub struct ManualSlot;
+
+// SAFETY: the slot is `'static`, and values are only dropped explicitly
+unsafe impl<T> OwnershipKind<T> for ManualSlot
+where
+    T: 'static,
+{
+    type Pointee = T;
+    type Husk = ();
+    type Inner = &'static mut MaybeUninit<T>;
+
+    fn split(val: Self::Inner) -> (Self::Husk, NonNull<Self::Pointee>) {
+        // The slot is initialized, since it's owned
+        ((), NonNull::from(val).cast())
+    }
+
+    unsafe fn join(_husk: Self::Husk, ptr: NonNull<Self::Pointee>) -> Self::Inner {
+        // SAFETY: pointer was obtained from a `&'static mut MaybeUninit<T>` in `.split()` and we
+        // are the only owner
+        unsafe { &mut *ptr.cast().as_ptr() }
+    }
+}
+
+impl<T> MoveOut<T> for ManualSlot
+where
+    T: 'static,
+{
+    fn move_out(val: Self::Inner) -> T {
+        // SAFETY: the slot is initialized while it's owned. It's left uninitialized, and the
+        // reference is consumed, so the value won't be read again.
+        unsafe { val.assume_init_read() }
+    }
+}
+
+impl<T> Owned<T, ManualSlot>
+where
+    T: 'static,
+{
+    /// Take ownership of the value in `slot`.
+    ///
+    /// # Safety
+    /// `slot` must be initialized. Responsibility for running the destructor passes to the
+    /// returned value, which only does so via [`.drop_in_place()`](Owned::drop_in_place).
+    pub unsafe fn from_slot(slot: &'static mut MaybeUninit<T>) -> Self {
+        // SAFETY: the slot is initialized, as guaranteed by the caller
+        unsafe { Self::from_inner(slot) }
+    }
+
+    /// Run the value's destructor, returning the now uninitialized slot
+    // The slot may be of no interest for the caller
+    #[allow(clippy::must_use_candidate)]
+    pub fn drop_in_place(self) -> &'static mut MaybeUninit<T> {
+        let slot = self.take_inner();
+        // SAFETY: the slot is initialized while it's owned, and ownership ends here
+        unsafe { slot.assume_init_drop() };
+        slot
+    }
+
+    /// Give up ownership without dropping the value, returning the still initialized slot.
+    /// Responsibility for the value passes to the caller.
+    #[must_use]
+    pub fn forget_into_slot(self) -> &'static mut MaybeUninit<T> {
+        self.take_inner()
+    }
+}
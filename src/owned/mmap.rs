@@ -0,0 +1,216 @@
+//! Ownership of memory-mapped files
+//!
+//! Writable (shared or copy-on-write) mappings are borrowed as byte slices:
+//! ```no_run
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! # fn main() -> std::io::Result<()> {
+//! let file = std::fs::File::open("dataset.bin")?;
+//! // SAFETY: nobody else modifies the file while it's mapped
+//! let owned = unsafe { Owned::map_copy(&file)? };
+//! let (husk, mut reference) = borrow!(owned);
+//! reference[0] = 0;
+//! let [ref1, ref2] = reference.split();
+//! assert_eq!(ref1[0], ref2[0]);
+//! let owned = ref1.join(ref2).reconstruct(husk);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Read-only mappings are borrowed as [`ReadOnlyBytes`], which can't be mutated even through a
+//! [`RefMut`](crate::RefMut):
+//! ```compile_fail
+//! # use nolife::*;
+//! # fn main() -> std::io::Result<()> {
+//! let file = std::fs::File::open("dataset.bin")?;
+//! let owned = unsafe { Owned::map_read_only(&file)? };
+//! let (husk, mut reference) = borrow!(owned);
+//! reference[0] = 0;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The mapping is unmapped when the [`Owned`] value is dropped. Dropping a husk leaks the mapping
+//! instead, since references into it may still exist. Joining a reference with the husk of another
+//! mapping panics, so that a mapping is never unmapped under live references:
+//! ```should_panic
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! # use memmap2::MmapMut;
+//! let mut borrowed = Vec::new();
+//! for len in [16, 32] {
+//!     let mmap = MmapMut::map_anon(len).unwrap();
+//!     borrowed.push(borrow!(Owned::from_mmap_mut(mmap)));
+//! }
+//! let (husk1, _ref1) = borrowed.remove(0);
+//! let (_husk2, ref2) = borrowed.remove(0);
+//! // Panics: both husks have the same brand, but the first one belongs to another mapping
+//! ref2.reconstruct(husk1);
+//! ```
+
+use std::{fs::File, io, mem::ManuallyDrop, ops::Deref, ptr::NonNull};
+
+use memmap2::{Mmap, MmapMut, MmapOptions};
+
+use super::{Owned, OwnershipKind};
+
+/// Ownership kind for writable memory maps
+pub struct MmapKind;
+
+/// Ownership kind for read-only memory maps, which are borrowed as [`ReadOnlyBytes`]
+pub struct ReadOnlyMmapKind;
+
+/// Husk of a borrowed memory map, holding the mapping handle
+pub struct MmapHusk<M> {
+    mmap: ManuallyDrop<M>,
+    // Address and length of the mapping, to check that the husk is joined with its own mapping
+    addr: usize,
+    len: usize,
+}
+
+impl<M> MmapHusk<M>
+where
+    M: Deref<Target = [u8]>,
+{
+    fn new(mmap: M) -> Self {
+        Self {
+            addr: mmap.as_ptr().addr(),
+            len: mmap.len(),
+            mmap: ManuallyDrop::new(mmap),
+        }
+    }
+
+    /// # Panics
+    /// Panics if the husk belongs to another mapping, which can happen when husks with the same
+    /// brand are swapped.
+    fn join(self, ptr: *mut [u8]) -> M {
+        assert!(
+            ptr.cast::<u8>().addr() == self.addr && ptr.len() == self.len,
+            "husk belongs to another mapping"
+        );
+        ManuallyDrop::into_inner(self.mmap)
+    }
+}
+
+/// Bytes of a read-only mapping. Only shared access to the bytes is possible.
+#[repr(transparent)]
+pub struct ReadOnlyBytes([u8]);
+
+impl Deref for ReadOnlyBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for ReadOnlyBytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+// SAFETY: the mapping is only unmapped by its handle, which is leaked if the husk is dropped
+unsafe impl OwnershipKind<[u8]> for MmapKind {
+    type Pointee = [u8];
+    type Husk = MmapHusk<MmapMut>;
+    type Inner = MmapMut;
+
+    fn split(mut val: Self::Inner) -> (Self::Husk, NonNull<Self::Pointee>) {
+        // Mapped memory doesn't move together with the handle
+        let ptr = NonNull::from(&mut *val);
+        (MmapHusk::new(val), ptr)
+    }
+
+    unsafe fn join(husk: Self::Husk, ptr: NonNull<Self::Pointee>) -> Self::Inner {
+        husk.join(ptr.as_ptr())
+    }
+}
+
+// SAFETY: the mapping is only unmapped by its handle, which is leaked if the husk is dropped
+unsafe impl OwnershipKind<[u8]> for ReadOnlyMmapKind {
+    type Pointee = ReadOnlyBytes;
+    type Husk = MmapHusk<Mmap>;
+    type Inner = Mmap;
+
+    fn split(val: Self::Inner) -> (Self::Husk, NonNull<Self::Pointee>) {
+        let ptr = NonNull::from(&*val);
+        // SAFETY: `ReadOnlyBytes` is a transparent wrapper around `[u8]` which never gives out
+        // mutable access to the bytes
+        let ptr = unsafe { NonNull::new_unchecked(ptr.as_ptr() as *mut ReadOnlyBytes) };
+        (MmapHusk::new(val), ptr)
+    }
+
+    unsafe fn join(husk: Self::Husk, ptr: NonNull<Self::Pointee>) -> Self::Inner {
+        husk.join(ptr.as_ptr() as *mut [u8])
+    }
+}
+
+impl Owned<[u8], MmapKind> {
+    /// Create a private copy-on-write mapping of `file`. Changes are not written back.
+    ///
+    /// # Errors
+    /// Returns an error if the file can't be mapped.
+    ///
+    /// # Safety
+    /// The file must not be modified while it's mapped (see [`MmapOptions::map_copy`]).
+    pub unsafe fn map_copy(file: &File) -> io::Result<Self> {
+        // SAFETY: guaranteed by the caller
+        let mmap = unsafe { MmapOptions::new().map_copy(file)? };
+        Ok(Self::from_mmap_mut(mmap))
+    }
+
+    /// Create a shared writable mapping of `file`. Changes are written back to the file.
+    ///
+    /// # Errors
+    /// Returns an error if the file can't be mapped, e.g. when it's not opened for writing.
+    ///
+    /// # Safety
+    /// The file must not be modified by anyone else while it's mapped (see [`MmapMut::map_mut`]).
+    pub unsafe fn map_mut(file: &File) -> io::Result<Self> {
+        // SAFETY: guaranteed by the caller
+        let mmap = unsafe { MmapMut::map_mut(file)? };
+        Ok(Self::from_mmap_mut(mmap))
+    }
+
+    /// Take ownership of an existing writable mapping
+    #[must_use]
+    pub fn from_mmap_mut(mmap: MmapMut) -> Self {
+        // SAFETY: the mapping is owned by its handle
+        unsafe { Self::from_inner(mmap) }
+    }
+
+    /// Give up branded ownership, returning the mapping handle
+    #[must_use]
+    pub fn into_mmap_mut(self) -> MmapMut {
+        self.take_inner()
+    }
+}
+
+impl Owned<[u8], ReadOnlyMmapKind> {
+    /// Create a read-only mapping of `file`
+    ///
+    /// # Errors
+    /// Returns an error if the file can't be mapped.
+    ///
+    /// # Safety
+    /// The file must not be modified while it's mapped (see [`Mmap::map`]).
+    pub unsafe fn map_read_only(file: &File) -> io::Result<Self> {
+        // SAFETY: guaranteed by the caller
+        let mmap = unsafe { Mmap::map(file)? };
+        Ok(Self::from_mmap(mmap))
+    }
+
+    /// Take ownership of an existing read-only mapping
+    #[must_use]
+    pub fn from_mmap(mmap: Mmap) -> Self {
+        // SAFETY: the mapping is owned by its handle
+        unsafe { Self::from_inner(mmap) }
+    }
+
+    /// Give up branded ownership, returning the mapping handle
+    #[must_use]
+    pub fn into_mmap(self) -> Mmap {
+        self.take_inner()
+    }
+}
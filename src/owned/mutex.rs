@@ -0,0 +1,160 @@
+//! Ownership of values behind an `Arc<Mutex<T>>`
+//!
+//! The mutex is locked for as long as the value is borrowed, and unlocked on reconstruction. Inside
+//! the locked region references can be split and joined as usual:
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! # use std::sync::{Arc, Mutex};
+//! let mutex = Arc::new(Mutex::new(vec![1]));
+//! let owned = Owned::from_mutex(Arc::clone(&mutex));
+//! let (husk, mut reference) = borrow!(owned);
+//! assert!(mutex.try_lock().is_err());
+//! reference.push(2);
+//! let [ref1, ref2] = reference.split();
+//! assert_eq!(ref1.len(), ref2.len());
+//! let owned = ref1.join(ref2).reconstruct(husk);
+//! assert_eq!(*mutex.lock().unwrap(), [1, 2]);
+//!
+//! // Trying to split while the mutex is locked elsewhere fails
+//! let guard = mutex.lock().unwrap();
+//! let owned = try_borrow!(owned).err().unwrap();
+//! drop(guard);
+//! drop(mutex);
+//! assert_eq!(owned.try_into_inner().ok(), Some(vec![1, 2]));
+//! ```
+//! Dropping the husk while the value is borrowed leaves the mutex locked forever, and so does
+//! joining a reference with the husk of another mutex, which panics:
+//! ```should_panic
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! # use std::sync::{Arc, Mutex};
+//! let mut borrowed = Vec::new();
+//! for value in [1, 2] {
+//!     borrowed.push(borrow!(Owned::from_mutex(Arc::new(Mutex::new(value)))));
+//! }
+//! let (husk1, _ref1) = borrowed.remove(0);
+//! let (_husk2, ref2) = borrowed.remove(0);
+//! // Panics: both husks have the same brand, but the first one belongs to another mutex
+//! ref2.reconstruct(husk1);
+//! ```
+
+use std::{
+    mem::ManuallyDrop,
+    ptr::NonNull,
+    sync::{Arc, Mutex, MutexGuard, PoisonError, TryLockError},
+};
+
+use super::{Owned, OwnershipKind, TryMoveOut};
+
+/// Ownership kind for values behind a shared [`Mutex`], which is locked while they're borrowed
+pub struct MutexKind;
+
+/// Husk of a borrowed [`Mutex`] value, holding the lock.
+///
+/// Dropping it leaks both the lock and the mutex, since borrowed memory may still be in use.
+pub struct MutexHusk<T>
+where
+    T: 'static,
+{
+    guard: ManuallyDrop<MutexGuard<'static, T>>,
+    mutex: ManuallyDrop<Arc<Mutex<T>>>,
+}
+
+impl MutexKind {
+    fn lock_static<T>(mutex: &Arc<Mutex<T>>) -> &'static Mutex<T>
+    where
+        T: 'static,
+    {
+        // SAFETY: the guard is only stored in the husk together with the `Arc`, and both are
+        // leaked if the husk is dropped
+        unsafe { &*Arc::as_ptr(mutex) }
+    }
+
+    fn split_locked<T>(
+        mutex: Arc<Mutex<T>>,
+        mut guard: MutexGuard<'static, T>,
+    ) -> (MutexHusk<T>, NonNull<T>)
+    where
+        T: 'static,
+    {
+        let ptr = NonNull::from(&mut *guard);
+        let husk = MutexHusk {
+            guard: ManuallyDrop::new(guard),
+            mutex: ManuallyDrop::new(mutex),
+        };
+        (husk, ptr)
+    }
+}
+
+// SAFETY: the lock is held while split, and both the guard and the `Arc` are leaked if the husk is
+// dropped
+unsafe impl<T> OwnershipKind<T> for MutexKind
+where
+    T: 'static,
+{
+    type Pointee = T;
+    type Husk = MutexHusk<T>;
+    type Inner = Arc<Mutex<T>>;
+
+    /// Blocks until the mutex is unlocked
+    fn split(val: Self::Inner) -> (Self::Husk, NonNull<Self::Pointee>) {
+        let guard = Self::lock_static(&val)
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        Self::split_locked(val, guard)
+    }
+
+    fn try_split(val: Self::Inner) -> Result<(Self::Husk, NonNull<Self::Pointee>), Self::Inner> {
+        let guard = match Self::lock_static(&val).try_lock() {
+            Ok(guard) => guard,
+            Err(TryLockError::Poisoned(err)) => err.into_inner(),
+            Err(TryLockError::WouldBlock) => return Err(val),
+        };
+        Ok(Self::split_locked(val, guard))
+    }
+
+    /// # Panics
+    /// Panics if the husk belongs to another mutex, which can happen when husks with the same
+    /// brand are swapped.
+    unsafe fn join(husk: Self::Husk, ptr: NonNull<Self::Pointee>) -> Self::Inner {
+        assert!(
+            ptr == NonNull::from(&**husk.guard),
+            "husk belongs to another mutex"
+        );
+        drop(ManuallyDrop::into_inner(husk.guard));
+        ManuallyDrop::into_inner(husk.mutex)
+    }
+}
+
+impl<T> TryMoveOut<T> for MutexKind
+where
+    T: 'static,
+{
+    fn try_move_out(val: Self::Inner) -> Result<T, Self::Inner> {
+        Arc::try_unwrap(val).map(|mutex| mutex.into_inner().unwrap_or_else(PoisonError::into_inner))
+    }
+}
+
+impl<T> Owned<T, MutexKind>
+where
+    T: 'static,
+{
+    /// Take ownership of a shared [`Mutex`]
+    pub fn from_mutex(mutex: Arc<Mutex<T>>) -> Self {
+        // SAFETY: `MutexKind` holds the lock whenever it's split
+        unsafe { Self::from_inner(mutex) }
+    }
+
+    /// Access the underlying [`Mutex`], e.g. to clone it
+    #[must_use]
+    pub fn as_mutex(&self) -> &Arc<Mutex<T>> {
+        &self.inner
+    }
+
+    /// Give up branded ownership, returning the underlying [`Mutex`]
+    #[must_use]
+    pub fn into_mutex(self) -> Arc<Mutex<T>> {
+        self.take_inner()
+    }
+}
@@ -0,0 +1,220 @@
+//! Two independently owned values sharing one allocation
+//!
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! # use std::{alloc::{GlobalAlloc, Layout, System}, sync::atomic::{AtomicUsize, Ordering}};
+//! # struct Counting;
+//! # static ALLOCS: AtomicUsize = AtomicUsize::new(0);
+//! # static DEALLOCS: AtomicUsize = AtomicUsize::new(0);
+//! # unsafe impl GlobalAlloc for Counting {
+//! #     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+//! #         ALLOCS.fetch_add(1, Ordering::Relaxed);
+//! #         unsafe { System.alloc(layout) }
+//! #     }
+//! #     unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+//! #         DEALLOCS.fetch_add(1, Ordering::Relaxed);
+//! #         unsafe { System.dealloc(ptr, layout) }
+//! #     }
+//! # }
+//! # #[global_allocator]
+//! # static GLOBAL: Counting = Counting;
+//! # let counts = || (ALLOCS.load(Ordering::Relaxed), DEALLOCS.load(Ordering::Relaxed));
+//! let before = counts();
+//! let (left, right) = Owned::pair(1_u64, [0_u8; 3]);
+//! assert_eq!(counts(), (before.0 + 1, before.1));
+//!
+//! let (husk, mut reference) = borrow!(right);
+//! reference[0] = 1;
+//! let right = reference.reconstruct(husk);
+//! drop(right);
+//! assert_eq!(counts(), (before.0 + 1, before.1));
+//! assert_eq!(left.into_inner(), 1);
+//! assert_eq!(counts(), (before.0 + 1, before.1 + 1));
+//!
+//! // Halves can be dropped in any order
+//! let (left, right) = Owned::pair(String::from("left"), String::from("right"));
+//! drop(left);
+//! drop(right);
+//! ```
+//!
+//! Joining a half with the husk of another one panics, so that the allocation is never released
+//! through a wrong header:
+//! ```should_panic
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! let mut borrowed = Vec::new();
+//! for value in [1, 2] {
+//!     let (_, right) = Owned::pair((), value);
+//!     borrowed.push(borrow!(right));
+//! }
+//! let (husk1, _ref1) = borrowed.remove(0);
+//! let (_husk2, ref2) = borrowed.remove(0);
+//! // Panics: both husks have the same brand, but the first one belongs to another pair
+//! ref2.reconstruct(husk1);
+//! ```
+
+use std::{
+    mem::{ManuallyDrop, MaybeUninit},
+    ptr::{self, NonNull},
+    sync::atomic::{self, AtomicUsize, Ordering},
+};
+
+use super::{MoveOut, Owned, OwnershipKind};
+
+/// Ownership kind for one half of a [pair](Owned::pair)
+pub struct PairKind;
+
+/// One half of a pair, owning its value and sharing the allocation with the other half
+pub struct PairBox<T> {
+    value: NonNull<T>,
+    header: NonNull<PairHeader>,
+}
+
+/// Husk of a borrowed half of a pair
+pub struct PairHusk {
+    value: NonNull<()>,
+    header: NonNull<PairHeader>,
+}
+
+struct PairHeader {
+    refs: AtomicUsize,
+    free: unsafe fn(NonNull<PairHeader>),
+}
+
+#[repr(C)]
+struct PairBlock<A, B> {
+    header: PairHeader,
+    first: MaybeUninit<A>,
+    second: MaybeUninit<B>,
+}
+
+// SAFETY: `PairBox<T>` owns a `T`, and the shared header is synchronized
+unsafe impl<T: Send> Send for PairBox<T> {}
+// SAFETY: `PairBox<T>` only gives out shared access to `T` through `&self`
+unsafe impl<T: Sync> Sync for PairBox<T> {}
+// SAFETY: the husk never touches the header or the value
+unsafe impl Send for PairHusk {}
+// SAFETY: the husk never touches the header or the value
+unsafe impl Sync for PairHusk {}
+
+impl PairHeader {
+    /// Release one half, freeing the allocation if it was the last one
+    ///
+    /// # Safety
+    /// Must be called at most once per half, after its value was dropped or moved out.
+    unsafe fn release(header: NonNull<Self>) {
+        // SAFETY: the allocation is alive until both halves are released
+        let free = unsafe {
+            let header = header.as_ref();
+            if header.refs.fetch_sub(1, Ordering::Release) != 1 {
+                return;
+            }
+            header.free
+        };
+        atomic::fence(Ordering::Acquire);
+        // SAFETY: both halves are released, so we're the only ones accessing the allocation
+        unsafe { free(header) };
+    }
+}
+
+impl<A, B> PairBlock<A, B> {
+    /// # Safety
+    /// `header` must be the header of a `PairBlock<A, B>` allocated by [`Owned::pair`] whose values
+    /// were already dropped or moved out.
+    unsafe fn free(header: NonNull<PairHeader>) {
+        // SAFETY: the header is the first field of a `repr(C)` block, and `MaybeUninit` fields
+        // won't drop anything
+        drop(unsafe { Box::from_raw(header.cast::<Self>().as_ptr()) });
+    }
+}
+
+impl<T> Drop for PairBox<T> {
+    fn drop(&mut self) {
+        // SAFETY: `PairBox` always points to an initialized value which it exclusively owns, and
+        // releases its half exactly once
+        unsafe {
+            ptr::drop_in_place(self.value.as_ptr());
+            PairHeader::release(self.header);
+        }
+    }
+}
+
+// SAFETY: each half owns a distinct value, and the allocation is only freed after both halves are
+// released
+unsafe impl<T> OwnershipKind<T> for PairKind {
+    type Pointee = T;
+    type Husk = PairHusk;
+    type Inner = PairBox<T>;
+
+    fn split(val: Self::Inner) -> (Self::Husk, NonNull<Self::Pointee>) {
+        let val = ManuallyDrop::new(val);
+        let husk = PairHusk {
+            value: val.value.cast(),
+            header: val.header,
+        };
+        (husk, val.value)
+    }
+
+    /// # Panics
+    /// Panics if the husk belongs to another half, which can happen when husks with the same brand
+    /// are swapped.
+    unsafe fn join(husk: Self::Husk, ptr: NonNull<Self::Pointee>) -> Self::Inner {
+        assert!(ptr.cast() == husk.value, "husk belongs to another pair");
+        PairBox {
+            value: ptr,
+            header: husk.header,
+        }
+    }
+}
+
+impl<T> MoveOut<T> for PairKind {
+    fn move_out(val: Self::Inner) -> T {
+        let val = ManuallyDrop::new(val);
+        // SAFETY: `PairBox` points to an initialized value, and we've forgotten it, so the value
+        // won't be dropped twice
+        unsafe {
+            let value = ptr::read(val.value.as_ptr());
+            PairHeader::release(val.header);
+            value
+        }
+    }
+}
+
+impl<A> Owned<A, PairKind> {
+    /// Allocate `first` and `second` together, returning an independent owner for each of them.
+    ///
+    /// The allocation is freed when both halves are dropped or moved out of.
+    pub fn pair<B>(first: A, second: B) -> (Self, Owned<B, PairKind>) {
+        let block = Box::new(PairBlock {
+            header: PairHeader {
+                refs: AtomicUsize::new(2),
+                free: PairBlock::<A, B>::free,
+            },
+            first: MaybeUninit::new(first),
+            second: MaybeUninit::new(second),
+        });
+        let block = Box::into_raw(block);
+        // SAFETY: `Box::into_raw` never returns null, and field pointers are derived from it
+        let (header, first_ptr, second_ptr) = unsafe {
+            (
+                NonNull::new_unchecked(ptr::addr_of_mut!((*block).header)),
+                NonNull::new_unchecked(ptr::addr_of_mut!((*block).first).cast::<A>()),
+                NonNull::new_unchecked(ptr::addr_of_mut!((*block).second).cast::<B>()),
+            )
+        };
+        // SAFETY: both values are initialized, and each half owns one of them
+        unsafe {
+            (
+                Self::from_inner(PairBox {
+                    value: first_ptr,
+                    header,
+                }),
+                Owned::from_inner(PairBox {
+                    value: second_ptr,
+                    header,
+                }),
+            )
+        }
+    }
+}
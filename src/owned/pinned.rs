@@ -0,0 +1,292 @@
+//! Ownership of pinned heap-allocated values
+//!
+//! References to pinned values point to an opaque [`Pinned`] and only give out [`Pin`]ned
+//! references to the value itself:
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! # use std::marker::PhantomPinned;
+//! struct Node {
+//!     value: i32,
+//!     _pin: PhantomPinned,
+//! }
+//!
+//! let owned = heap_pin!(Node { value: 0, _pin: PhantomPinned });
+//! let (husk, mut reference) = borrow!(owned);
+//! // SAFETY: we're not moving the node
+//! unsafe { reference.pinned_mut().get_unchecked_mut().value += 1 };
+//! let [ref1, ref2] = reference.split();
+//! assert_eq!(ref1.pinned().value, 1);
+//! let owned: Owned<Node, PinnedHeap> = ref1.join(ref2).reconstruct(husk);
+//! ```
+//! Values can only be moved out when they're [`Unpin`]:
+//! ```
+//! # use nolife::*;
+//! assert_eq!(heap_pin!(0).into_inner(), 0);
+//! ```
+//! ```compile_fail
+//! # use nolife::*;
+//! # use std::marker::PhantomPinned;
+//! heap_pin!(PhantomPinned).into_inner();
+//! ```
+
+use std::{marker::PhantomData, pin::Pin, ptr::NonNull};
+
+use super::{MoveOut, Owned, OwnershipKind};
+
+/// Pinned heap-allocated ownership kind
+pub struct PinnedHeap;
+
+/// Opaque pointee of references to [`PinnedHeap`] values. Use [`Ref::pinned`](crate::Ref::pinned)
+/// and [`RefMut::pinned_mut`](crate::Ref::pinned_mut) to access the value.
+///
+/// It's zero-sized, so mutable references to it can't be used to move the value.
+pub struct Pinned<T> {
+    value: PhantomData<T>,
+}
+
+// SAFETY: the pointer owns the allocation until it's passed back to `Box::from_raw`, and the
+// value is never moved while pinned
+unsafe impl<T> OwnershipKind<T> for PinnedHeap {
+    type Pointee = Pinned<T>;
+    type Husk = ();
+    type Inner = Pin<Box<T>>;
+
+    fn split(val: Self::Inner) -> (Self::Husk, NonNull<Self::Pointee>) {
+        // SAFETY: the value stays pinned, since references only give out `Pin`s to it
+        let raw = Box::into_raw(unsafe { Pin::into_inner_unchecked(val) });
+        // SAFETY: Box<T> is guaranteed not to be null
+        let ptr = unsafe { NonNull::new_unchecked(raw) };
+        ((), ptr.cast())
+    }
+
+    unsafe fn join(_husk: Self::Husk, ptr: NonNull<Self::Pointee>) -> Self::Inner {
+        // SAFETY: pointer was obtained from `.split()` and we are the only owner. The value was
+        // pinned before splitting.
+        unsafe { Pin::new_unchecked(Box::from_raw(ptr.cast::<T>().as_ptr())) }
+    }
+}
+
+impl<T> MoveOut<T> for PinnedHeap
+where
+    T: Unpin,
+{
+    fn move_out(val: Self::Inner) -> T {
+        *Pin::into_inner(val)
+    }
+}
+
+impl<T> Owned<T, PinnedHeap> {
+    /// Move `val` to the heap and pin it there, like [`Box::pin`]
+    #[inline]
+    #[must_use]
+    pub fn pin(val: T) -> Self {
+        Self::from_pin(Box::pin(val))
+    }
+
+    /// Take ownership of a pinned box
+    #[inline]
+    #[must_use]
+    pub fn from_pin(val: Pin<Box<T>>) -> Self {
+        // SAFETY: it's always safe to take ownership of a pinned box
+        unsafe { Self::from_inner(val) }
+    }
+}
+
+/// Create a new pinned [`Owned`] value on the heap
+#[macro_export]
+macro_rules! heap_pin {
+    ($val:expr) => {
+        $crate::Owned::<_, $crate::PinnedHeap>::pin($val)
+    };
+}
+
+/// An implementation detail of [`pin_project_ref!`]: `Unpin` exactly when the pinned fields are.
+/// The lifetime keeps the bound from being trivially false for concrete `!Unpin` types.
+#[doc(hidden)]
+pub struct PinnedField<'pin, T>(PhantomData<&'pin ()>, T);
+
+/// Declare a struct whose pinned references can be projected to its fields, like `pin-project`.
+/// Fields marked with `#[pin]` are projected to [`Pin<&mut Field>`](Pin), and the rest to
+/// `&mut Field`. The struct gets a `.project()` method on `Pin<&mut Self>`, which returns the
+/// projection struct named by `#[project = Name]`, so fields of values created with
+/// [`heap_pin!`] can be accessed through [`.pinned_mut()`](crate::Ref::pinned_mut) without
+/// `unsafe`:
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// use std::{
+///     future::{self, Future},
+///     pin::Pin,
+///     task::{Context, Poll, Waker},
+/// };
+///
+/// pin_project_ref! {
+///     #[project = CountedProjection]
+///     struct Counted<F> {
+///         #[pin]
+///         future: F,
+///         polls: u32,
+///     }
+/// }
+///
+/// impl<F: Future> Future for Counted<F> {
+///     type Output = F::Output;
+///
+///     fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<F::Output> {
+///         let this = self.project();
+///         *this.polls += 1;
+///         this.future.poll(context)
+///     }
+/// }
+///
+/// let mut ready = false;
+/// let inner = future::poll_fn(move |_| {
+///     if ready {
+///         Poll::Ready(5)
+///     } else {
+///         ready = true;
+///         Poll::Pending
+///     }
+/// });
+/// // `async` blocks aren't `Unpin`, and neither is `Counted` of them
+/// let owned = heap_pin!(Counted { future: async { inner.await * 2 }, polls: 0 });
+/// let (husk, mut reference) = borrow!(owned);
+/// let mut context = Context::from_waker(Waker::noop());
+/// while reference.pinned_mut().poll(&mut context).is_pending() {}
+/// assert_eq!(reference.pinned_mut().project().polls, &mut 2);
+/// let owned = reference.reconstruct(husk);
+/// ```
+///
+/// Pinned fields can't be accessed as unpinned:
+/// ```compile_fail
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// # use std::marker::PhantomPinned;
+/// pin_project_ref! {
+///     #[project = NodeProjection]
+///     struct Node {
+///         #[pin]
+///         pinned: PhantomPinned,
+///         value: i32,
+///     }
+/// }
+///
+/// let (husk, mut reference) = borrow!(heap_pin!(Node { pinned: PhantomPinned, value: 0 }));
+/// let pinned: &mut PhantomPinned = reference.pinned_mut().project().pinned;
+/// ```
+///
+/// And the struct can't implement [`Drop`], which could move pinned fields out:
+/// ```compile_fail
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// # use std::marker::PhantomPinned;
+/// pin_project_ref! {
+///     #[project = NodeProjection]
+///     struct Node {
+///         #[pin]
+///         pinned: PhantomPinned,
+///     }
+/// }
+///
+/// impl Drop for Node {
+///     fn drop(&mut self) {}
+/// }
+/// ```
+#[macro_export]
+macro_rules! pin_project_ref {
+    (
+        #[project = $projection:ident]
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident $(<$($generic:ident),* $(,)?>)? {
+            $(
+                $(#[$pin:ident])?
+                $field_vis:vis $field:ident: $ty:ty
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $name $(<$($generic),*>)? {
+            $($field_vis $field: $ty),*
+        }
+
+        /// Projection of a pinned reference to its fields
+        #[allow(dead_code)]
+        $vis struct $projection<'__pin, $($($generic),*)?>
+        where
+            $($($generic: '__pin),*)?
+        {
+            $(
+                $field_vis $field:
+                    $crate::__pin_project_ref_field!('__pin [$($pin)?] $ty),
+            )*
+        }
+
+        impl $(<$($generic),*>)? $name $(<$($generic),*>)? {
+            /// Project a pinned reference to references to the fields
+            $vis fn project<'__pin>(
+                self: ::core::pin::Pin<&'__pin mut Self>,
+            ) -> $projection<'__pin, $($($generic),*)?> {
+                // SAFETY: the value is never moved out of the pinned reference, and `#[pin]`
+                // fields are only given out pinned. The struct is `Unpin` only if they are, and
+                // it can't implement `Drop`.
+                let Self { $($field),* } = unsafe { self.get_unchecked_mut() };
+                $projection {
+                    $($field: $crate::__pin_project_ref_value!([$($pin)?] $field)),*
+                }
+            }
+        }
+
+        impl<'__pin, $($($generic),*)?> ::core::marker::Unpin for $name $(<$($generic),*>)?
+        where
+            $($crate::PinnedField<'__pin, $crate::__pin_project_ref_unpin!([$($pin)?] $ty)>:
+                ::core::marker::Unpin),*
+        {
+        }
+
+        const _: () = {
+            // Implementing `Drop` makes this impl conflict with the blanket one
+            trait MustNotImplDrop {}
+            #[allow(drop_bounds)]
+            impl<T> MustNotImplDrop for T where T: ::core::ops::Drop {}
+            impl $(<$($generic),*>)? MustNotImplDrop for $name $(<$($generic),*>)? {}
+        };
+    };
+}
+
+/// An implementation detail of [`pin_project_ref!`]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pin_project_ref_field {
+    ($lifetime:lifetime [pin] $ty:ty) => {
+        ::core::pin::Pin<&$lifetime mut $ty>
+    };
+    ($lifetime:lifetime [] $ty:ty) => {
+        &$lifetime mut $ty
+    };
+}
+
+/// An implementation detail of [`pin_project_ref!`]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pin_project_ref_value {
+    ([pin] $field:ident) => {
+        // SAFETY: the field is structurally pinned
+        unsafe { ::core::pin::Pin::new_unchecked($field) }
+    };
+    ([] $field:ident) => {
+        $field
+    };
+}
+
+/// An implementation detail of [`pin_project_ref!`]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pin_project_ref_unpin {
+    ([pin] $ty:ty) => {
+        $ty
+    };
+    ([] $ty:ty) => {
+        ()
+    };
+}
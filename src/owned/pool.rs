@@ -0,0 +1,183 @@
+//! Ownership of values living in a [`Pool`] of recycled allocations
+//!
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! let pool = Pool::new();
+//! for round in 0..10 {
+//!     let values: Vec<_> = (0..4).map(|i| pool.alloc(round * 4 + i)).collect();
+//!     let (husk, mut reference) = borrow!(pool.alloc(0));
+//!     *reference += 1;
+//!     assert_eq!(reference.reconstruct(husk).into_inner(), 1);
+//!     drop(values);
+//! }
+//! // Freed slots are reused, so the pool never grows beyond its high-water mark
+//! assert_eq!(pool.capacity(), 5);
+//! ```
+//!
+//! References don't carry a lifetime, so the pool aborts the process if it's dropped while any of
+//! its values are still borrowed.
+//!
+//! Joining a reference with the husk of another value panics, so that the slot is never returned
+//! to a wrong pool:
+//! ```should_panic
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! let (pool1, pool2) = (Pool::new(), Pool::new());
+//! let mut borrowed = Vec::new();
+//! for pool in [&pool1, &pool2] {
+//!     borrowed.push(borrow!(pool.alloc(0)));
+//! }
+//! let (husk1, _ref1) = borrowed.remove(0);
+//! let (_husk2, ref2) = borrowed.remove(0);
+//! // Panics: both husks have the same brand, but the first one belongs to another value
+//! ref2.reconstruct(husk1);
+//! ```
+
+use std::{
+    cell::{Cell, RefCell},
+    marker::PhantomData,
+    mem::{self, MaybeUninit},
+    process,
+    ptr::{self, NonNull},
+};
+
+use super::{MoveOut, Owned, OwnershipKind};
+
+/// Single-threaded pool of same-sized allocations
+pub struct Pool<T> {
+    slots: RefCell<Vec<NonNull<MaybeUninit<T>>>>,
+    free: RefCell<Vec<NonNull<MaybeUninit<T>>>>,
+    borrowed: Cell<usize>,
+}
+
+/// Ownership kind for values living in a [`Pool`]
+pub struct PoolKind<'pool>(PhantomData<&'pool ()>);
+
+/// Value living in a [`Pool`]. Drops the value and returns its slot to the pool when dropped.
+pub struct PoolBox<'pool, T> {
+    pool: &'pool Pool<T>,
+    ptr: NonNull<T>,
+}
+
+/// Husk of a borrowed [`PoolBox`]
+pub struct PoolHusk<'pool, T> {
+    pool: &'pool Pool<T>,
+    ptr: NonNull<T>,
+}
+
+impl<T> Pool<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            slots: RefCell::new(Vec::new()),
+            free: RefCell::new(Vec::new()),
+            borrowed: Cell::new(0),
+        }
+    }
+
+    /// Number of slots ever allocated by this pool
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.slots.borrow().len()
+    }
+
+    /// Move `val` into the pool, reusing a free slot if there is one
+    pub fn alloc(&self, val: T) -> Owned<T, PoolKind<'_>> {
+        let slot = self.free.borrow_mut().pop().unwrap_or_else(|| {
+            let slot = NonNull::from(Box::leak(Box::new(MaybeUninit::uninit())));
+            self.slots.borrow_mut().push(slot);
+            slot
+        });
+        let ptr = slot.cast::<T>();
+        // SAFETY: free slots are allocated, but don't contain a value
+        unsafe { ptr.as_ptr().write(val) };
+        let inner = PoolBox { pool: self, ptr };
+        // SAFETY: we've just initialized this slot and nothing else owns it
+        unsafe { Owned::from_inner(inner) }
+    }
+
+    fn recycle(&self, ptr: NonNull<T>) {
+        self.free.borrow_mut().push(ptr.cast());
+    }
+}
+
+impl<T> Default for Pool<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for Pool<T> {
+    fn drop(&mut self) {
+        if self.borrowed.get() != 0 {
+            // Some `Ref` may still point into this pool, and we can't stop it from being used
+            process::abort();
+        }
+        for slot in self.slots.get_mut().drain(..) {
+            // SAFETY: slots are allocated by `Box` and values in them are never dropped by the pool
+            drop(unsafe { Box::from_raw(slot.as_ptr()) });
+        }
+    }
+}
+
+impl<T> Drop for PoolBox<'_, T> {
+    fn drop(&mut self) {
+        // Returns the slot even if the destructor panics
+        struct Recycle<'a, 'pool, T>(&'a PoolBox<'pool, T>);
+        impl<T> Drop for Recycle<'_, '_, T> {
+            fn drop(&mut self) {
+                self.0.pool.recycle(self.0.ptr);
+            }
+        }
+
+        let _recycle = Recycle(self);
+        // SAFETY: `PoolBox` always points to an initialized value which it exclusively owns
+        unsafe { ptr::drop_in_place(self.ptr.as_ptr()) };
+    }
+}
+
+// SAFETY: the slot is only recycled by `PoolBox`, and the pool aborts if it's dropped while
+// borrowed
+unsafe impl<'pool, T> OwnershipKind<T> for PoolKind<'pool>
+where
+    T: 'pool,
+{
+    type Pointee = T;
+    type Husk = PoolHusk<'pool, T>;
+    type Inner = PoolBox<'pool, T>;
+
+    fn split(val: Self::Inner) -> (Self::Husk, NonNull<Self::Pointee>) {
+        let (pool, ptr) = (val.pool, val.ptr);
+        mem::forget(val);
+        pool.borrowed.set(pool.borrowed.get() + 1);
+        (PoolHusk { pool, ptr }, ptr)
+    }
+
+    /// # Panics
+    /// Panics if the husk belongs to another value, which can happen when husks with the same
+    /// brand are swapped.
+    unsafe fn join(husk: Self::Husk, ptr: NonNull<Self::Pointee>) -> Self::Inner {
+        assert!(ptr == husk.ptr, "husk belongs to another value");
+        husk.pool.borrowed.set(husk.pool.borrowed.get() - 1);
+        PoolBox {
+            pool: husk.pool,
+            ptr,
+        }
+    }
+}
+
+impl<'pool, T> MoveOut<T> for PoolKind<'pool>
+where
+    T: 'pool,
+{
+    fn move_out(val: Self::Inner) -> T {
+        let (pool, ptr) = (val.pool, val.ptr);
+        mem::forget(val);
+        // SAFETY: `PoolBox` points to an initialized value, and we've forgotten it, so the value
+        // won't be dropped twice
+        let value = unsafe { ptr::read(ptr.as_ptr()) };
+        pool.recycle(ptr);
+        value
+    }
+}
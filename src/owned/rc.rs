@@ -0,0 +1,133 @@
+//! Ownership of values behind an [`Rc`]
+//!
+//! An [`Owned`] value of this kind may share its allocation with other [`Rc`]s, but can only be
+//! borrowed while it's the only one:
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! # use std::rc::Rc;
+//! let owned = Owned::from_rc(Rc::new(0));
+//! let other = Rc::clone(owned.as_rc());
+//! let owned = try_borrow!(owned).err().unwrap();
+//! drop(other);
+//! let (husk, mut reference) = try_borrow!(owned).ok().unwrap();
+//! *reference += 1;
+//! let owned = reference.reconstruct(husk);
+//! assert_eq!(owned.try_into_inner().ok(), Some(1));
+//! ```
+
+use std::{ptr::NonNull, rc::Rc};
+
+use super::{Heap, Owned, OwnershipKind, TryMoveOut};
+
+/// Reference-counted ownership kind, which can only be split and moved out of while the [`Rc`] is
+/// unique
+pub struct RcKind;
+
+// SAFETY: splitting requires the `Rc` to be unique, and `Rc::into_raw` keeps the allocation alive
+// until `Rc::from_raw`
+unsafe impl<T> OwnershipKind<T> for RcKind
+where
+    T: ?Sized,
+{
+    type Pointee = T;
+    type Husk = ();
+    type Inner = Rc<T>;
+
+    /// # Panics
+    /// Panics if there are other [`Rc`]s or [`Weak`](std::rc::Weak)s pointing to the same
+    /// allocation. Use [`try_borrow!`](crate::try_borrow!) to handle that case.
+    fn split(mut val: Self::Inner) -> (Self::Husk, NonNull<Self::Pointee>) {
+        // Weak pointers also count, since they could be upgraded while we're borrowed
+        assert!(
+            Rc::get_mut(&mut val).is_some(),
+            "tried to split a shared `Rc`"
+        );
+        // SAFETY: `Rc::into_raw` never returns null
+        let ptr = unsafe { NonNull::new_unchecked(Rc::into_raw(val).cast_mut()) };
+        ((), ptr)
+    }
+
+    fn try_split(
+        mut val: Self::Inner,
+    ) -> Result<(Self::Husk, NonNull<Self::Pointee>), Self::Inner> {
+        if Rc::get_mut(&mut val).is_none() {
+            return Err(val);
+        }
+        Ok(Self::split(val))
+    }
+
+    unsafe fn join(_husk: Self::Husk, ptr: NonNull<Self::Pointee>) -> Self::Inner {
+        // SAFETY: pointer was obtained from `Rc::into_raw` in `.split()` and we are the only owner
+        unsafe { Rc::from_raw(ptr.as_ptr()) }
+    }
+}
+
+impl<T> TryMoveOut<T> for RcKind {
+    fn try_move_out(val: Self::Inner) -> Result<T, Self::Inner> {
+        Rc::try_unwrap(val)
+    }
+}
+
+impl<T> Owned<T, RcKind>
+where
+    T: ?Sized,
+{
+    /// Take ownership of an [`Rc`], which may still be shared or unsized
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// # use std::rc::Rc;
+    /// let (husk, mut reference) = borrow!(Owned::from_rc(Rc::<str>::from("hello")));
+    /// reference.make_ascii_uppercase();
+    /// assert_eq!(&*reference.reconstruct(husk).into_rc(), "HELLO");
+    /// ```
+    pub fn from_rc(rc: Rc<T>) -> Self {
+        // SAFETY: `RcKind` checks uniqueness whenever it's split or moved out of
+        unsafe { Self::from_inner(rc) }
+    }
+
+    /// Access the underlying [`Rc`], e.g. to clone it
+    #[must_use]
+    pub fn as_rc(&self) -> &Rc<T> {
+        &self.inner
+    }
+
+    /// Give up branded ownership, returning the underlying [`Rc`]
+    #[must_use]
+    pub fn into_rc(self) -> Rc<T> {
+        self.take_inner()
+    }
+}
+
+impl<T> Owned<T, Heap>
+where
+    T: ?Sized,
+{
+    /// Give up exclusive ownership, moving the value into a new [`Rc`]
+    #[must_use]
+    pub fn into_rc(self) -> Rc<T> {
+        Rc::from(self.take_inner())
+    }
+}
+
+impl<T> Owned<T, Heap> {
+    /// Regain exclusive ownership of the value if there are no other [`Rc`]s pointing to it
+    ///
+    /// ```
+    /// # use nolife::*;
+    /// # use std::rc::Rc;
+    /// let shared = Owned::new(5).into_rc();
+    /// let other = Rc::clone(&shared);
+    /// let shared = Owned::try_from_rc(shared).err().unwrap();
+    /// drop(other);
+    /// assert_eq!(Owned::try_from_rc(shared).ok().map(Owned::into_inner), Some(5));
+    /// ```
+    ///
+    /// # Errors
+    /// Gives the [`Rc`] back if it's shared.
+    pub fn try_from_rc(rc: Rc<T>) -> Result<Self, Rc<T>> {
+        Rc::try_unwrap(rc).map(Self::new)
+    }
+}
@@ -0,0 +1,33 @@
+//! Serializing and deserializing owned values with [`serde`]
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{Heap, Owned};
+
+/// Serializes the value, like `Box<T>` does
+impl<T> Serialize for Owned<T, Heap>
+where
+    T: ?Sized + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        T::serialize(&self.inner, serializer)
+    }
+}
+
+/// Deserializes the value into a fresh heap allocation, like `Box<T>` does. Unsized values like
+/// `str` and slices are supported too.
+impl<'de, T> Deserialize<'de> for Owned<T, Heap>
+where
+    T: ?Sized,
+    Box<T>: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Box::deserialize(deserializer).map(Self::from_box)
+    }
+}
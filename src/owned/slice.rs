@@ -0,0 +1,50 @@
+//! Heap-allocated slices
+//!
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! let owned = heap_slice![0u8; 4];
+//! let (husk, mut reference) = borrow!(owned);
+//! reference[1] = 1;
+//! reference.reverse();
+//! let [ref1, ref2] = reference.split();
+//! assert_eq!(ref1.len(), 4);
+//! let owned = ref1.join(ref2).reconstruct(husk);
+//! assert_eq!(owned.into_vec(), [0, 0, 1, 0]);
+//! ```
+
+use super::{Heap, Owned};
+
+impl<T> Owned<[T], Heap> {
+    /// Take ownership of a boxed slice
+    #[must_use]
+    pub fn from_boxed_slice(val: Box<[T]>) -> Self {
+        Self::from_box(val)
+    }
+
+    /// Take ownership of vector elements, dropping its spare capacity
+    #[must_use]
+    pub fn from_vec(val: Vec<T>) -> Self {
+        Self::from_boxed_slice(val.into_boxed_slice())
+    }
+
+    /// Obtain ownership over the contained slice
+    #[must_use]
+    pub fn into_boxed_slice(self) -> Box<[T]> {
+        self.take_inner()
+    }
+
+    /// Obtain ownership over the contained slice as a [`Vec`], reusing the allocation
+    #[must_use]
+    pub fn into_vec(self) -> Vec<T> {
+        self.into_boxed_slice().into_vec()
+    }
+}
+
+/// Create a new [`Owned`] slice on the heap. Accepts the same syntax as [`vec!`].
+#[macro_export]
+macro_rules! heap_slice {
+    ($($tt:tt)*) => {
+        $crate::Owned::<[_], $crate::Heap>::from_vec(::std::vec![$($tt)*])
+    };
+}
@@ -0,0 +1,180 @@
+//! Ownership of values living in the caller's stack frame
+//!
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! stack!(let owned = 0);
+//! let (husk, mut reference) = borrow!(owned);
+//! *reference += 1;
+//! let [ref1, ref2] = reference.split();
+//! assert_eq!(*ref1, *ref2);
+//! let owned = ref1.join(ref2).reconstruct(husk);
+//! assert_eq!(owned.into_inner(), 1);
+//! ```
+//!
+//! Stack-allocated values can't outlive their frame:
+//! ```compile_fail
+//! # use nolife::*;
+//! fn escape() -> Owned<i32, Stack<'static>> {
+//!     stack!(let owned = 0);
+//!     owned
+//! }
+//! ```
+//!
+//! References don't carry a lifetime, so they could be smuggled out of the frame. To keep this
+//! sound, the frame aborts the process if it ends while its value is still borrowed, i.e. when the
+//! husk was not reconstructed. This also applies to unwinding: if a panic passes through the frame
+//! while references are alive, the process is aborted instead of dropping the value or freeing the
+//! storage under live references.
+
+use std::{
+    marker::PhantomData,
+    mem::{self, MaybeUninit},
+    process,
+    ptr::{self, NonNull},
+};
+
+use super::{MoveOut, Owned, OwnershipKind};
+
+/// Stack-allocated ownership kind. The value lives in the caller's frame and can't outlive it.
+/// Created by the [`stack!`](crate::stack!) macro or by [`Stack::scope`].
+pub struct Stack<'frame>(PhantomData<&'frame mut ()>);
+
+/// Storage for a stack-allocated value, created by the [`stack!`](crate::stack!) macro
+#[doc(hidden)]
+pub struct StackSlot<T> {
+    value: MaybeUninit<T>,
+    borrowed: bool,
+}
+
+/// Initialized stack-allocated value. Drops the value when dropped.
+pub struct StackBox<'frame, T> {
+    slot: NonNull<StackSlot<T>>,
+    frame: PhantomData<(&'frame mut (), T)>,
+}
+
+/// Husk of a borrowed stack-allocated value
+pub struct StackHusk<'frame, T> {
+    slot: NonNull<StackSlot<T>>,
+    frame: PhantomData<&'frame mut ()>,
+}
+
+impl<T> StackSlot<T> {
+    /// Create an empty slot
+    ///
+    /// # Safety
+    /// The slot must be dropped rather than forgotten: its destructor is what keeps references
+    /// from outliving it. The [`stack!`](crate::stack!) macro ensures this by binding the slot to
+    /// a local that the caller can't name.
+    #[must_use]
+    pub unsafe fn new() -> Self {
+        Self {
+            value: MaybeUninit::uninit(),
+            borrowed: false,
+        }
+    }
+}
+
+impl<T> Drop for StackSlot<T> {
+    fn drop(&mut self) {
+        if self.borrowed {
+            // Some `Ref` may still point into this slot, and we can't stop it from being used
+            process::abort();
+        }
+    }
+}
+
+impl<T> Drop for StackBox<'_, T> {
+    fn drop(&mut self) {
+        // SAFETY: `StackBox` always points to an initialized value which it exclusively owns
+        unsafe { ptr::drop_in_place(ptr::addr_of_mut!((*self.slot.as_ptr()).value).cast::<T>()) }
+    }
+}
+
+// SAFETY: the slot is marked as borrowed while split, and aborts if its frame ends in that state
+unsafe impl<'frame, T> OwnershipKind<T> for Stack<'frame> {
+    type Pointee = T;
+    type Husk = StackHusk<'frame, T>;
+    type Inner = StackBox<'frame, T>;
+
+    fn split(val: Self::Inner) -> (Self::Husk, NonNull<Self::Pointee>) {
+        let slot = val.slot;
+        mem::forget(val);
+        // SAFETY: `StackBox` points to a live slot
+        let ptr = unsafe {
+            (*slot.as_ptr()).borrowed = true;
+            NonNull::new_unchecked(ptr::addr_of_mut!((*slot.as_ptr()).value).cast::<T>())
+        };
+        (
+            StackHusk {
+                slot,
+                frame: PhantomData,
+            },
+            ptr,
+        )
+    }
+
+    unsafe fn join(husk: Self::Husk, _ptr: NonNull<Self::Pointee>) -> Self::Inner {
+        // SAFETY: husk points to a live slot and no references exist anymore
+        unsafe { (*husk.slot.as_ptr()).borrowed = false };
+        StackBox {
+            slot: husk.slot,
+            frame: PhantomData,
+        }
+    }
+}
+
+impl<T> MoveOut<T> for Stack<'_> {
+    fn move_out(val: Self::Inner) -> T {
+        let slot = val.slot;
+        mem::forget(val);
+        // SAFETY: `StackBox` points to an initialized value, and we've forgotten it, so the value
+        // won't be dropped twice
+        unsafe { ptr::read(ptr::addr_of!((*slot.as_ptr()).value).cast::<T>()) }
+    }
+}
+
+impl<'frame, T> Owned<T, Stack<'frame>> {
+    /// Move `val` into `slot`, taking ownership of it. Used by the [`stack!`](crate::stack!)
+    /// macro.
+    ///
+    /// # Panics
+    /// Panics if `slot` is still borrowed by a previous value whose husk was forgotten.
+    #[doc(hidden)]
+    pub fn new_in_slot(slot: &'frame mut StackSlot<T>, val: T) -> Self {
+        assert!(!slot.borrowed, "stack slot is still borrowed");
+        slot.value = MaybeUninit::new(val);
+        let inner = StackBox {
+            slot: NonNull::from(slot),
+            frame: PhantomData,
+        };
+        // SAFETY: the slot now holds an initialized value which isn't owned by anything else
+        unsafe { Self::from_inner(inner) }
+    }
+}
+
+impl Stack<'_> {
+    /// Run `f` with a stack-allocated [`Owned`] value which can't escape it.
+    ///
+    /// ```
+    /// # use nolife::*;
+    /// let doubled = Stack::scope(21, |owned| owned.into_inner() * 2);
+    /// assert_eq!(doubled, 42);
+    /// ```
+    pub fn scope<T, R>(val: T, f: impl for<'frame> FnOnce(Owned<T, Stack<'frame>>) -> R) -> R {
+        // SAFETY: the slot is a local of this function, so it's dropped when the function returns
+        // or unwinds
+        let mut slot = unsafe { StackSlot::new() };
+        f(Owned::new_in_slot(&mut slot, val))
+    }
+}
+
+/// Create a new [`Owned`] value in the current stack frame
+#[macro_export]
+macro_rules! stack {
+    (let $name:ident = $val:expr) => {
+        // SAFETY: macro hygiene keeps the caller from naming `slot`, so it can't be forgotten
+        let mut slot = unsafe { $crate::StackSlot::new() };
+        let $name = $crate::Owned::<_, $crate::Stack<'_>>::new_in_slot(&mut slot, $val);
+    };
+}
@@ -0,0 +1,70 @@
+//! Ownership of values behind a `&'static mut` reference
+//!
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! let leaked: &'static mut Vec<i32> = Box::leak(Box::new(vec![1]));
+//! // SAFETY: nothing else has access to the leaked value
+//! let owned = unsafe { Owned::from_static_mut(leaked) };
+//! let (husk, mut reference) = borrow!(owned);
+//! reference.push(2);
+//! let [ref1, ref2] = reference.split();
+//! let leaked = ref1.join(ref2).reconstruct(husk).into_static_mut();
+//! assert_eq!(leaked, &[1, 2]);
+//! ```
+//! The value can't be moved out of a static:
+//! ```compile_fail
+//! # use nolife::*;
+//! let owned = unsafe { Owned::from_static_mut(Box::leak(Box::new(0))) };
+//! owned.into_inner();
+//! ```
+
+use std::ptr::NonNull;
+
+use super::{Owned, OwnershipKind};
+
+/// Ownership kind for values which live forever, e.g. in a `static` or a leaked [`Box`]. Dropping
+/// such a value doesn't run its destructor.
+pub struct StaticKind;
+
+// SAFETY: the reference is `'static` and exclusive
+unsafe impl<T> OwnershipKind<T> for StaticKind
+where
+    T: ?Sized + 'static,
+{
+    type Pointee = T;
+    type Husk = ();
+    type Inner = &'static mut T;
+
+    fn split(val: Self::Inner) -> (Self::Husk, NonNull<Self::Pointee>) {
+        ((), NonNull::from(val))
+    }
+
+    unsafe fn join(_husk: Self::Husk, ptr: NonNull<Self::Pointee>) -> Self::Inner {
+        // SAFETY: pointer was obtained from a `&'static mut T` in `.split()` and we are the only
+        // owner
+        unsafe { &mut *ptr.as_ptr() }
+    }
+}
+
+impl<T> Owned<T, StaticKind>
+where
+    T: ?Sized + 'static,
+{
+    /// Take ownership of a value which lives forever.
+    ///
+    /// # Safety
+    /// The caller must guarantee exclusivity: no other code may access the value until it's given
+    /// back by [`.into_static_mut()`](Owned::into_static_mut). This matters when `val` was
+    /// obtained from a `static mut`, which anything else could also access.
+    pub unsafe fn from_static_mut(val: &'static mut T) -> Self {
+        // SAFETY: exclusivity is guaranteed by the caller
+        unsafe { Self::from_inner(val) }
+    }
+
+    /// Give up branded ownership, returning the underlying reference
+    #[must_use]
+    pub fn into_static_mut(self) -> &'static mut T {
+        self.take_inner()
+    }
+}
@@ -0,0 +1,175 @@
+//! Ownership of values living in a fixed-capacity [`StaticPool`], which doesn't need an allocator
+//!
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! static POOL: StaticPool<u32, 2> = StaticPool::new();
+//!
+//! let first = POOL.alloc(1).ok().unwrap();
+//! let second = POOL.alloc(2).ok().unwrap();
+//! // The pool is exhausted, so the value is given back
+//! assert_eq!(POOL.alloc(3).err(), Some(3));
+//!
+//! let (husk, mut reference) = borrow!(first);
+//! *reference += 10;
+//! let [ref1, ref2] = reference.split();
+//! assert_eq!(ref1.join(ref2).reconstruct(husk).into_inner(), 11);
+//! // The slot of the first value is free again
+//! let third = POOL.alloc(3).ok().unwrap();
+//! drop((second, third));
+//! ```
+//!
+//! Joining a reference with the husk of another value panics, so that a slot is never freed while
+//! it's still borrowed:
+//! ```should_panic
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! static POOL: StaticPool<u32, 2> = StaticPool::new();
+//!
+//! let mut borrowed = Vec::new();
+//! for value in [1, 2] {
+//!     borrowed.push(borrow!(POOL.alloc(value).ok().unwrap()));
+//! }
+//! let (husk1, _ref1) = borrowed.remove(0);
+//! let (_husk2, ref2) = borrowed.remove(0);
+//! // Panics: both husks have the same brand, but the first one belongs to another value
+//! ref2.reconstruct(husk1);
+//! ```
+
+use std::{
+    cell::UnsafeCell,
+    mem::{self, MaybeUninit},
+    ptr::{self, NonNull},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use super::{MoveOut, Owned, OwnershipKind};
+
+/// Pool of `N` slots for values of type `T`, intended to be put in a `static`
+pub struct StaticPool<T, const N: usize> {
+    slots: [UnsafeCell<MaybeUninit<T>>; N],
+    used: [AtomicBool; N],
+}
+
+/// Ownership kind for values living in a [`StaticPool`]
+pub struct StaticPoolKind;
+
+/// Value living in a [`StaticPool`]. Drops the value and frees its slot when dropped.
+pub struct StaticPoolBox<T> {
+    used: &'static AtomicBool,
+    ptr: NonNull<T>,
+}
+
+/// Husk of a borrowed [`StaticPoolBox`]. Dropping it leaks the slot.
+pub struct StaticPoolHusk {
+    used: &'static AtomicBool,
+    // Address of the slot, to check that the husk is joined with its own value
+    addr: usize,
+}
+
+// SAFETY: every slot is only accessible through the single `Owned` value which claimed it, which
+// may be moved to another thread
+unsafe impl<T, const N: usize> Sync for StaticPool<T, N> where T: Send {}
+
+impl<T, const N: usize> StaticPool<T, N>
+where
+    T: 'static,
+{
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            slots: [const { UnsafeCell::new(MaybeUninit::uninit()) }; N],
+            used: [const { AtomicBool::new(false) }; N],
+        }
+    }
+
+    /// Move `val` into a free slot. Gives `val` back if all slots are used.
+    pub fn alloc(&'static self, val: T) -> Result<Owned<T, StaticPoolKind>, T> {
+        let Some((slot, used)) = self.slots.iter().zip(&self.used).find(|(_, used)| {
+            used.compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+                .is_ok()
+        }) else {
+            return Err(val);
+        };
+        // SAFETY: we've just claimed this slot, so nobody else is accessing it
+        let ptr = unsafe { NonNull::new_unchecked(slot.get()).cast::<T>() };
+        // SAFETY: see above
+        unsafe { ptr.as_ptr().write(val) };
+        // SAFETY: we've just initialized this slot and nothing else owns it
+        Ok(unsafe { Owned::from_inner(StaticPoolBox { used, ptr }) })
+    }
+}
+
+impl<T, const N: usize> Default for StaticPool<T, N>
+where
+    T: 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for StaticPoolBox<T> {
+    fn drop(&mut self) {
+        // Frees the slot even if the destructor panics
+        struct Release(&'static AtomicBool);
+        impl Drop for Release {
+            fn drop(&mut self) {
+                self.0.store(false, Ordering::Release);
+            }
+        }
+
+        let _release = Release(self.used);
+        // SAFETY: `StaticPoolBox` always points to an initialized value which it exclusively owns
+        unsafe { ptr::drop_in_place(self.ptr.as_ptr()) };
+    }
+}
+
+// SAFETY: the slot is only released by `StaticPoolBox`, so a dropped husk leaks it
+unsafe impl<T> OwnershipKind<T> for StaticPoolKind
+where
+    T: 'static,
+{
+    type Pointee = T;
+    type Husk = StaticPoolHusk;
+    type Inner = StaticPoolBox<T>;
+
+    fn split(val: Self::Inner) -> (Self::Husk, NonNull<Self::Pointee>) {
+        let (used, ptr) = (val.used, val.ptr);
+        mem::forget(val);
+        let husk = StaticPoolHusk {
+            used,
+            addr: ptr.as_ptr().addr(),
+        };
+        (husk, ptr)
+    }
+
+    /// # Panics
+    /// Panics if the husk belongs to another value, which can happen when husks with the same
+    /// brand are swapped.
+    unsafe fn join(husk: Self::Husk, ptr: NonNull<Self::Pointee>) -> Self::Inner {
+        assert!(
+            ptr.as_ptr().addr() == husk.addr,
+            "husk belongs to another value"
+        );
+        StaticPoolBox {
+            used: husk.used,
+            ptr,
+        }
+    }
+}
+
+impl<T> MoveOut<T> for StaticPoolKind
+where
+    T: 'static,
+{
+    fn move_out(val: Self::Inner) -> T {
+        let (used, ptr) = (val.used, val.ptr);
+        mem::forget(val);
+        // SAFETY: `StaticPoolBox` points to an initialized value, and we've forgotten it, so the
+        // value won't be dropped twice
+        let value = unsafe { ptr::read(ptr.as_ptr()) };
+        used.store(false, Ordering::Release);
+        value
+    }
+}
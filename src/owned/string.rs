@@ -0,0 +1,52 @@
+//! Heap-allocated strings
+//!
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! let owned = heap_str!("hello");
+//! let (husk, mut reference) = borrow!(owned);
+//! reference.make_ascii_uppercase();
+//! let [ref1, ref2] = reference.split();
+//! assert_eq!(&*ref1, "HELLO");
+//! let owned = ref1.join(ref2).reconstruct(husk);
+//! let mut string = owned.into_string();
+//! string.push('!');
+//! assert_eq!(string, "HELLO!");
+//! ```
+
+use super::{Heap, Owned};
+
+impl Owned<str, Heap> {
+    /// Take ownership of a boxed string
+    #[must_use]
+    pub fn from_boxed_str(val: Box<str>) -> Self {
+        Self::from_box(val)
+    }
+
+    /// Take ownership of string contents, dropping its spare capacity
+    #[must_use]
+    pub fn from_string(val: String) -> Self {
+        Self::from_boxed_str(val.into_boxed_str())
+    }
+
+    /// Obtain ownership over the contained string
+    #[must_use]
+    pub fn into_boxed_str(self) -> Box<str> {
+        self.take_inner()
+    }
+
+    /// Obtain ownership over the contained string as a [`String`], reusing the allocation
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.into_boxed_str().into_string()
+    }
+}
+
+/// Create a new [`Owned`] string on the heap from anything convertible to a [`Box<str>`]
+#[macro_export]
+macro_rules! heap_str {
+    ($val:expr) => {{
+        let val: ::std::boxed::Box<str> = ::std::convert::Into::into($val);
+        $crate::Owned::<str, $crate::Heap>::from_boxed_str(val)
+    }};
+}
@@ -0,0 +1,110 @@
+//! Ownership of [`String`] contents, with the husk keeping the spare capacity
+//!
+//! References see the contents as a [`str`], so mutations through them always keep the string
+//! valid UTF-8:
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! let mut string = String::with_capacity(16);
+//! string.push_str("hello");
+//! let (husk, mut reference) = borrow!(Owned::wrap_string(string));
+//! reference.make_ascii_uppercase();
+//! let [ref1, ref2] = reference.split();
+//! assert_eq!(&*ref1, "HELLO");
+//! let mut string = ref1.join(ref2).reconstruct(husk).into_inner();
+//! assert_eq!(string.capacity(), 16);
+//! string.push('!');
+//! assert_eq!(string, "HELLO!");
+//! ```
+//! Dropping the husk while the string is borrowed leaks it. Joining a reference with the husk of
+//! another string panics:
+//! ```should_panic
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! let mut borrowed = Vec::new();
+//! for string in ["a".to_owned(), String::with_capacity(16)] {
+//!     borrowed.push(borrow!(Owned::wrap_string(string)));
+//! }
+//! let (husk1, _ref1) = borrowed.remove(0);
+//! let (_husk2, ref2) = borrowed.remove(0);
+//! // Panics: both husks have the same brand, but the first one belongs to another string
+//! ref2.reconstruct(husk1);
+//! ```
+
+use std::{
+    mem::ManuallyDrop,
+    ptr::{self, NonNull},
+};
+
+use super::{CloneInner, MoveOut, Owned, OwnershipKind};
+
+/// Ownership kind for [`String`]s, which are borrowed as [`str`]s
+pub struct StringKind;
+
+/// Husk of a borrowed [`String`], holding its capacity
+pub struct StringHusk {
+    // Address and length of the contents, to check that the husk is joined with its own string
+    addr: usize,
+    len: usize,
+    capacity: usize,
+}
+
+// SAFETY: the buffer is only freed by `String`, which is reassembled from the same raw parts
+unsafe impl OwnershipKind<String> for StringKind {
+    type Pointee = str;
+    type Husk = StringHusk;
+    type Inner = String;
+
+    fn split(val: Self::Inner) -> (Self::Husk, NonNull<Self::Pointee>) {
+        let mut bytes = ManuallyDrop::new(val.into_bytes());
+        let husk = StringHusk {
+            addr: bytes.as_ptr().addr(),
+            len: bytes.len(),
+            capacity: bytes.capacity(),
+        };
+        let slice = ptr::slice_from_raw_parts_mut(bytes.as_mut_ptr(), bytes.len());
+        // SAFETY: `Vec` pointer is never null, and the bytes are valid UTF-8 since they came from
+        // a `String`
+        let ptr = unsafe { NonNull::new_unchecked(slice as *mut str) };
+        (husk, ptr)
+    }
+
+    /// # Panics
+    /// Panics if the husk belongs to another string, which can happen when husks with the same
+    /// brand are swapped.
+    unsafe fn join(husk: Self::Husk, ptr: NonNull<Self::Pointee>) -> Self::Inner {
+        let len = (ptr.as_ptr() as *mut [u8]).len();
+        assert!(
+            ptr.cast::<u8>().as_ptr().addr() == husk.addr && len == husk.len,
+            "husk belongs to another string"
+        );
+        // SAFETY: pointer and length were obtained from a `String` in `.split()` together with
+        // capacity, and we are the only owner. References can only keep the contents valid UTF-8.
+        unsafe { String::from_raw_parts(ptr.cast::<u8>().as_ptr(), len, husk.capacity) }
+    }
+}
+
+impl MoveOut<String> for StringKind {
+    fn move_out(val: Self::Inner) -> String {
+        val
+    }
+}
+
+impl CloneInner<String> for StringKind {
+    fn clone_inner(val: &Self::Inner) -> Self::Inner {
+        val.clone()
+    }
+
+    fn clone_inner_from(dest: &mut Self::Inner, source: &Self::Inner) {
+        dest.clone_from(source);
+    }
+}
+
+impl Owned<String, StringKind> {
+    /// Take ownership of a [`String`], keeping its spare capacity
+    #[must_use]
+    pub fn wrap_string(val: String) -> Self {
+        // SAFETY: it's always safe to take ownership of a `String`
+        unsafe { Self::from_inner(val) }
+    }
+}
@@ -0,0 +1,110 @@
+//! Ownership of [`Vec`] elements, with the husk keeping the spare capacity
+//!
+//! References see the initialized elements as a slice, and the vector is reassembled from its raw
+//! parts on reconstruction. The round trip is covered by `tests/vec_raw_parts.rs`, which is meant
+//! to be run with `cargo miri test`:
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! let mut vec = Vec::with_capacity(16);
+//! vec.extend([3, 1, 2]);
+//! let (husk, mut reference) = borrow!(Owned::wrap_vec(vec));
+//! reference.sort_unstable();
+//! let [ref1, ref2] = reference.split();
+//! assert_eq!(&*ref1, [1, 2, 3]);
+//! let mut vec = ref1.join(ref2).reconstruct(husk).into_inner();
+//! assert_eq!(vec.capacity(), 16);
+//! vec.push(4);
+//! assert_eq!(vec, [1, 2, 3, 4]);
+//! ```
+//!
+//! Brands are unique per `borrow!` call site rather than per value, so husks of vectors borrowed
+//! in a loop can be mixed up. Joining a reference with a wrong husk panics instead of rebuilding
+//! the vector with a wrong capacity:
+//! ```should_panic
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! let mut borrowed = Vec::new();
+//! for vec in [vec![1], Vec::with_capacity(16)] {
+//!     borrowed.push(borrow!(Owned::wrap_vec(vec)));
+//! }
+//! let (husk1, _ref1) = borrowed.remove(0);
+//! let (_husk2, ref2) = borrowed.remove(0);
+//! // Panics: the husk belongs to another vector
+//! ref2.reconstruct(husk1);
+//! ```
+
+use std::{mem::ManuallyDrop, ptr::NonNull};
+
+use super::{CloneInner, MoveOut, Owned, OwnershipKind};
+
+/// Ownership kind for [`Vec`]s, which are borrowed as slices
+pub struct VecKind;
+
+/// Husk of a borrowed [`Vec`], holding its capacity
+pub struct VecHusk {
+    // Address and length of the elements, to check that the husk is joined with its own vector
+    addr: usize,
+    len: usize,
+    capacity: usize,
+}
+
+// SAFETY: the buffer is only freed by `Vec`, which is reassembled from the same raw parts
+unsafe impl<T> OwnershipKind<Vec<T>> for VecKind {
+    type Pointee = [T];
+    type Husk = VecHusk;
+    type Inner = Vec<T>;
+
+    fn split(val: Self::Inner) -> (Self::Husk, NonNull<Self::Pointee>) {
+        let mut val = ManuallyDrop::new(val);
+        // SAFETY: `Vec` pointer is never null
+        let ptr = unsafe { NonNull::new_unchecked(val.as_mut_ptr()) };
+        let husk = VecHusk {
+            addr: ptr.as_ptr().addr(),
+            len: val.len(),
+            capacity: val.capacity(),
+        };
+        (husk, NonNull::slice_from_raw_parts(ptr, val.len()))
+    }
+
+    /// # Panics
+    /// Panics if the husk belongs to another vector, which can happen when husks with the same
+    /// brand are swapped.
+    unsafe fn join(husk: Self::Husk, ptr: NonNull<Self::Pointee>) -> Self::Inner {
+        assert!(
+            ptr.cast::<T>().as_ptr().addr() == husk.addr && ptr.len() == husk.len,
+            "husk belongs to another vector"
+        );
+        // SAFETY: pointer and length were obtained from a `Vec` in `.split()` together with
+        // capacity, and we are the only owner
+        unsafe { Vec::from_raw_parts(ptr.cast::<T>().as_ptr(), ptr.len(), husk.capacity) }
+    }
+}
+
+impl<T> MoveOut<Vec<T>> for VecKind {
+    fn move_out(val: Self::Inner) -> Vec<T> {
+        val
+    }
+}
+
+impl<T> CloneInner<Vec<T>> for VecKind
+where
+    T: Clone,
+{
+    fn clone_inner(val: &Self::Inner) -> Self::Inner {
+        val.clone()
+    }
+
+    fn clone_inner_from(dest: &mut Self::Inner, source: &Self::Inner) {
+        dest.clone_from(source);
+    }
+}
+
+impl<T> Owned<Vec<T>, VecKind> {
+    /// Take ownership of a [`Vec`], keeping its spare capacity
+    #[must_use]
+    pub fn wrap_vec(val: Vec<T>) -> Self {
+        // SAFETY: it's always safe to take ownership of a `Vec`
+        unsafe { Self::from_inner(val) }
+    }
+}
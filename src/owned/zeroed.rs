@@ -0,0 +1,42 @@
+//! Zero-initialized heap values, which are never constructed on the stack
+//!
+//! ```
+//! # use nolife::*;
+//! // 16 MiB, which would overflow the stack with `heap!([0.0; 4 << 20])` in debug builds
+//! let owned = heap_zeroed!([f32; 4 << 20]);
+//! let (husk, mut reference) = borrow!(owned);
+//! assert!(reference.iter().all(|&x| x == 0.0));
+//! reference[0] = 1.0;
+//! let owned = reference.reconstruct(husk);
+//! ```
+
+use bytemuck::Zeroable;
+
+use super::{Heap, Owned};
+
+impl<T> Owned<T, Heap>
+where
+    T: Zeroable,
+{
+    /// Allocate a zeroed value on the heap without constructing it on the stack first
+    #[must_use]
+    pub fn new_zeroed() -> Self {
+        // SAFETY: all-zero bit pattern is a valid `T`, since it's `Zeroable`
+        let val = unsafe { Box::<T>::new_zeroed().assume_init() };
+        Self::from_box(val)
+    }
+}
+
+/// Create a new zeroed [`Owned`] value on the heap. The type can be given explicitly as in
+/// `heap_zeroed!([u8; 1024])`, or inferred.
+///
+/// See [`Owned::new_zeroed`].
+#[macro_export]
+macro_rules! heap_zeroed {
+    () => {
+        $crate::heap_zeroed!(_)
+    };
+    ($ty:ty) => {
+        $crate::Owned::<$ty, $crate::Heap>::new_zeroed()
+    };
+}
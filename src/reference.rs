@@ -1,28 +1,231 @@
 use std::{
-    ops::{Deref, DerefMut},
-    ptr::NonNull,
+    any::Any,
+    array,
+    cell::Cell,
+    error::Error,
+    fmt,
+    marker::PhantomData,
+    mem::{self, MaybeUninit},
+    ops::{Deref, DerefMut, Index, IndexMut},
+    panic::{RefUnwindSafe, UnwindSafe},
+    pin::Pin,
+    ptr::{self, NonNull},
 };
 
+#[cfg(feature = "unsize")]
+use std::{marker::Unsize, ops::CoerceUnsized};
+
 use crate::{
-    brand::IsBrand,
-    owned::{Husk, OwnershipKind},
+    brand::{IsBrand, Owning, Reborrow},
+    owned::{Heap, Husk, MoveOut, OwnershipKind, Pinned, TrivialHusk},
     Owned,
 };
 
+#[cfg(feature = "tokio")]
+mod async_io;
+mod bundle;
+#[cfg(feature = "bytemuck")]
+mod bytes;
+#[cfg(feature = "fn_traits")]
+mod call;
+mod cmp;
+mod convert;
+mod describe;
+mod dynamic;
+mod elements;
+mod erased;
+mod exclusive;
+mod fields;
+mod frozen;
+mod future;
+mod io;
+mod iter;
+mod level;
+mod many;
+mod projection;
+#[cfg(feature = "serde")]
+mod serialize;
+mod shared;
+mod slice;
+#[cfg(feature = "futures")]
+mod stream;
+mod threads;
+#[cfg(feature = "bytemuck")]
+mod transparent;
+pub use bundle::{RefBundle, SameBrand};
+#[cfg(feature = "bytemuck")]
+pub use bytes::Bytes;
+pub use describe::RefDescription;
+pub use dynamic::{DynRef, JoinError, JoinErrorKind};
+pub use erased::{BrandMismatch, ErasedHusk, ErasedRef, ErasedRefMut};
+pub use exclusive::Exclusive;
+pub use fields::{Reunite, Split};
+pub use frozen::Frozen;
+pub use level::{CanJoin, CanSplit};
+pub use many::{GetManyError, Remainder};
+pub use projection::Projected;
+pub use shared::{Collector, SharedRef};
+pub use slice::{Chunks, IntoIterMut};
+use threads::Access;
+
 /// Borrowed reference. References of `LEVEL` 0 are mutable.
+///
+/// With the nightly `arbitrary_self_types` feature enabled in your crate, `Ref`s can be used as
+/// method receivers, since they implement [`Deref`]:
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// #![feature(arbitrary_self_types)]
+/// # use nolife::*;
+/// use nolife::brand::IsBrand;
+///
+/// struct Counter {
+///     ticks: u32,
+/// }
+///
+/// impl Counter {
+///     fn tick<B: IsBrand>(mut self: RefMut<Self, B>) -> RefMut<Self, B> {
+///         self.ticks += 1;
+///         self
+///     }
+///
+///     fn ticks<B: IsBrand, const LEVEL: usize>(self: &Ref<Self, B, LEVEL>) -> u32 {
+///         self.ticks
+///     }
+/// }
+///
+/// let (husk, reference) = borrow!(heap!(Counter { ticks: 0 }));
+/// let reference = reference.tick().tick();
+/// let [ref1, ref2] = reference.split();
+/// assert_eq!(ref1.ticks(), 2);
+/// let owned = ref1.join(ref2).reconstruct(husk);
+/// ```
+///
+/// Shared references only forbid mutation through the `Ref` itself, just like `&T` does. Values
+/// with interior mutability, like [`Cell`] or atomics, can be mutated through any of them:
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// use std::{cell::Cell, sync::atomic::{AtomicU32, Ordering}};
+///
+/// let (husk, reference) = borrow!(heap!((Cell::new(0), AtomicU32::new(0))));
+/// let [ref1, ref2] = reference.split();
+/// ref1.0.set(1);
+/// ref2.1.fetch_add(2, Ordering::Relaxed);
+/// let (cell, atomic) = ref1.join(ref2).reconstruct(husk).into_inner();
+/// assert_eq!(cell.get(), 1);
+/// assert_eq!(atomic.load(Ordering::Relaxed), 2);
+/// ```
+///
+/// # Layout
+/// `Ref` is guaranteed to have the same layout as [`NonNull<T>`], so it's pointer-sized for sized
+/// `T`, and `Option<Ref>` has no extra discriminant. Brands are always zero-sized. This is a
+/// part of the public API and won't change without a major version bump.
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// let (husk, reference) = borrow!(heap!(0_u8));
+/// assert_eq!(size_of_val(&reference), size_of::<usize>());
+/// let reference = Some(reference);
+/// assert_eq!(size_of_val(&reference), size_of::<usize>());
+/// let [ref1, ref2] = reference.unwrap().split();
+/// assert_eq!(size_of_val(&ref1), size_of::<usize>());
+/// let owned = ref1.join(ref2).reconstruct(husk);
+/// ```
+///
+/// # Variance
+/// `Ref` is invariant in `T`, like `&mut T`. Otherwise lifetimes inside the value could be
+/// shortened, e.g. to write a short-lived string into a reborrowed `RefMut<&'static str, _>`:
+/// ```compile_fail
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// use nolife::brand::IsBrand;
+///
+/// fn shorten<'a, B: IsBrand>(reference: RefMut<&'static str, B>) -> RefMut<&'a str, B> {
+///     reference
+/// }
+/// ```
+/// The same holds for all other types which can give mutable access back, like [`DynRef`] or
+/// [`IntoIterMut`]. [`Owned`] and [`Husk`] are invariant in `T` too.
+#[repr(transparent)]
 pub struct Ref<T, B, const LEVEL: usize>
 where
+    T: ?Sized,
     B: IsBrand,
 {
     ptr: NonNull<T>,
-    brand: B,
+    variance: Invariant<T>,
+    // Brands are zero-sized, so only the type is stored
+    brand: PhantomData<B>,
 }
 
 /// Mutable borrowed reference
 pub type RefMut<T, B> = Ref<T, B, 0>;
 
+/// Marker for types which give out mutable access to a `T`, making them invariant in `T`.
+/// Otherwise a reference to a `&'static str` could be turned into a reference to a `&'a str`,
+/// and a short-lived string written through it.
+type Invariant<T> = PhantomData<*mut T>;
+
+/// Compile-time check that a number of references can be tracked by levels
+struct PowerOfTwo<const N: usize>;
+
+impl<const N: usize> PowerOfTwo<N> {
+    const ASSERT: () = assert!(
+        N.is_power_of_two(),
+        "number of references must be a power of two"
+    );
+}
+
+/// Compile-time check that a brand is zero-sized, so it can be stored as [`PhantomData`]
+struct ZeroSized<B>(PhantomData<B>);
+
+impl<B> ZeroSized<B> {
+    const ASSERT: () = assert!(size_of::<B>() == 0, "brands must be zero-sized");
+}
+
+/// Compile-time check that `N` references of level `LEVEL` make up a whole mutable reference
+struct AllReferences<const LEVEL: usize, const N: usize>;
+
+impl<const LEVEL: usize, const N: usize> AllReferences<LEVEL, N> {
+    const ASSERT: () = assert!(
+        N.is_power_of_two() && N.ilog2() as usize == LEVEL,
+        "all references of the level must be given"
+    );
+}
+
+/// Compile-time check that an array has elements to find it back by
+struct NonEmpty<const N: usize>;
+
+impl<const N: usize> NonEmpty<N> {
+    const ASSERT: () = assert!(N > 0, "array must not be empty");
+}
+
+/// Level of references joined from references of `level`. Used in return types instead of
+/// `level - 1`, so that joining mutable references fails with a helpful message.
+#[doc(hidden)]
+#[inline]
+pub const fn parent_level(level: usize) -> usize {
+    assert!(
+        level > 0,
+        "cannot join mutable (level-0) references; did you mean `.reconstruct()`?"
+    );
+    level - 1
+}
+
+/// Whether both pointers cover the same memory
+#[inline]
+fn same_place<T>(first: NonNull<T>, second: NonNull<T>) -> bool
+where
+    T: ?Sized,
+{
+    // SAFETY: every `Ref` allows shared access
+    ptr::addr_eq(first.as_ptr(), second.as_ptr())
+        && unsafe { size_of_val(first.as_ref()) == size_of_val(second.as_ref()) }
+}
+
 impl<T, B, const LEVEL: usize> Ref<T, B, LEVEL>
 where
+    T: ?Sized,
     B: IsBrand,
 {
     /// Create a new `Ref` with given `ptr` and `brand`. This is extremely unsafe and probably will
@@ -31,71 +234,1321 @@ where
     /// # Safety
     /// 1. `ptr` must be owned by an [`Owned`](crate::Owned) value and obtained by calling
     ///    [`Owned::split`](crate::Owned::split) with the same `brand`
-    /// 2. There could be only one `Ref` of level 0. Any further `Ref`s are obtained by splitting
-    ///    or joining other `Ref`s. `Ref` obtained by splitting must have greater level. `Ref`
+    /// 2. There could be only one `Ref` of level 0, except for references to disjoint elements
+    ///    branded with [`Element`](crate::brand::Element). Any further `Ref`s are obtained by
+    ///    splitting or joining other `Ref`s. `Ref` obtained by splitting must have greater level. `Ref`
     ///    obtained by joining may have its level decreased by one.
-    pub unsafe fn new(ptr: NonNull<T>, brand: B) -> Self {
-        Self { ptr, brand }
+    #[inline]
+    pub const unsafe fn new(ptr: NonNull<T>, brand: B) -> Self {
+        let () = ZeroSized::<B>::ASSERT;
+        // Brands have no destructors, but that can't be known in `const fn`
+        mem::forget(brand);
+        Self {
+            ptr,
+            variance: PhantomData,
+            brand: PhantomData,
+        }
+    }
+
+    /// Take the brand out of this reference, consuming it
+    // The reference must be consumed, so the brand isn't duplicated
+    #[allow(clippy::unused_self)]
+    fn into_brand(self) -> B {
+        // SAFETY: brands are zero-sized, and this reference is consumed, so the brand is moved
+        // rather than duplicated
+        unsafe { B::conjure() }
+    }
+
+    /// Turn this reference into a raw pointer, e.g. to pass it through FFI. `Ref` is a thin
+    /// wrapper around the pointer, and brands are zero-sized, so the pointer is exactly the address
+    /// of the value. The reference can be obtained back with [`Ref::from_raw`].
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// use std::ffi::c_void;
+    ///
+    /// extern "C" fn call_with(callback: extern "C" fn(*mut c_void), user_data: *mut c_void) {
+    ///     callback(user_data);
+    /// }
+    ///
+    /// extern "C" fn increment(user_data: *mut c_void) {
+    ///     // SAFETY: `user_data` points to an `i32` we have exclusive access to
+    ///     unsafe { *user_data.cast::<i32>() += 1 };
+    /// }
+    ///
+    /// let (husk, reference) = borrow!(heap!(41));
+    /// let ptr = reference.into_raw();
+    /// call_with(increment, ptr.as_ptr().cast());
+    /// // SAFETY: `ptr` was obtained from `.into_raw()` of a reference with the same brand and level
+    /// let reference = unsafe { RefMut::from_raw(ptr) };
+    /// assert_eq!(reference.reconstruct(husk).into_inner(), 42);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn into_raw(self) -> NonNull<T> {
+        self.ptr
+    }
+
+    /// Obtain a reference back from a pointer returned by [`.into_raw()`](Ref::into_raw). The
+    /// brand and level are inferred or given with a turbofish.
+    ///
+    /// # Safety
+    /// `ptr` must be obtained from [`.into_raw()`](Ref::into_raw) of a `Ref` with the same brand
+    /// and level, and can only be turned back into a reference once
+    #[inline]
+    #[must_use]
+    pub unsafe fn from_raw(ptr: NonNull<T>) -> Self {
+        // SAFETY: brands are zero-sized, and the reference with this brand was consumed by
+        // `.into_raw()`
+        unsafe { Self::new(ptr, B::conjure()) }
+    }
+
+    /// Pointer to the value, without consuming the reference
+    ///
+    /// The pointer must not be used to access the value after this reference is consumed, and
+    /// writing through it is only allowed while this is the only reference, same as through
+    /// [`.as_mut()`](Ref::as_mut).
+    #[inline]
+    #[must_use]
+    pub const fn as_ptr(&self) -> NonNull<T> {
+        self.ptr
+    }
+
+    /// Address of the value. References to zero-sized values may share the address with unrelated
+    /// values, so it only identifies the value if `T` isn't zero-sized.
+    #[inline]
+    #[must_use]
+    pub fn addr(&self) -> usize {
+        self.ptr.as_ptr().cast::<()>().addr()
+    }
+
+    /// Obtain a plain shared reference, same as [`Deref`].
+    ///
+    /// The plain reference borrows this `Ref`, so it can only be held until this `Ref` is
+    /// consumed by [`.join()`](Ref::join), [`.reconstruct()`](Ref::reconstruct) or other
+    /// methods taking `self`:
+    /// ```compile_fail
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap!(5));
+    /// let plain = reference.as_ref();
+    /// let owned = reference.reconstruct(husk);
+    /// assert_eq!(*plain, 5);
+    /// ```
+    // Unlike `AsRef`, which could be implemented for many targets, this is never ambiguous
+    #[allow(clippy::should_implement_trait)]
+    #[inline]
+    #[must_use]
+    pub const fn as_ref(&self) -> &T {
+        // SAFETY: only references pointing to `.ptr` currently are non-zero-LEVEL `Ref`s which do
+        // not allow obtaining mutable references (or we are the only zero-LEVEL `Ref` which is
+        // also OK)
+        unsafe { self.ptr.as_ref() }
     }
 
     /// Split this reference into two immutable references with incremented LEVEL
+    ///
+    /// Splitting is `const`, so it can be done in `const fn`s:
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// use nolife::brand::IsBrand;
+    ///
+    /// const fn halves<B: IsBrand>(reference: RefMut<u8, B>) -> [Ref<u8, B, 1>; 2] {
+    ///     reference.split()
+    /// }
+    ///
+    /// let (husk, reference) = borrow!(heap!(5));
+    /// let [left, right] = halves(reference);
+    /// let owned = left.join(right).reconstruct(husk);
+    /// ```
     // Note: this doesn't use tuple since it seems to make typechecker unreasonably angry
-    pub fn split(self) -> [Ref<T, B, { LEVEL + 1 }>; 2] {
-        // SAFETY: we're using `.duplicate()` to split a reference
-        let (brand1, brand2) = unsafe { self.brand.duplicate() };
-        // SAFETY: if this `Ref` was created safely, calling `::new()` with the same parameters is
-        // safe, since we're splitting `Ref` while increasing level
-        unsafe { [Ref::new(self.ptr, brand1), Ref::new(self.ptr, brand2)] }
+    #[inline]
+    #[must_use]
+    pub const fn split(self) -> [Ref<T, B, { LEVEL + 1 }>; 2] {
+        // `.duplicate()` can't be called in `const fn`, but only the type of the brand is stored
+        let brand = PhantomData;
+        // Splitting a reference while increasing level is what `.duplicate()` is for, and this
+        // `Ref` is consumed
+        [
+            Ref {
+                ptr: self.ptr,
+                variance: PhantomData,
+                brand,
+            },
+            Ref {
+                ptr: self.ptr,
+                variance: PhantomData,
+                brand,
+            },
+        ]
     }
 
-    /// Join this reference with other reference of same level, decrementing level
-    pub fn join(self, _: Self) -> Ref<T, B, { LEVEL - 1 }> {
+    /// Join this reference with other reference of same level, decrementing level. Mutable
+    /// references can't be joined, they need to be reconstructed instead:
+    /// ```compile_fail
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// # use std::ptr::NonNull;
+    /// let (husk, reference) = borrow!(heap!(5));
+    /// let ptr = NonNull::from(&*reference);
+    /// // SAFETY: never compiles
+    /// let other = unsafe { Ref::from_raw(ptr) };
+    /// // error: cannot join mutable (level-0) references; did you mean `.reconstruct()`?
+    /// let reference = reference.join(other);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the references point to different places, which is only possible for references
+    /// to different elements branded with [`Element`](crate::brand::Element), or if [`Ref::new`]
+    /// was misused. Since element references rely on it, the check is done in release builds too,
+    /// as are the checks of [`.join_n()`](Ref::join_n) and all [`Reunite`] implementations.
+    /// Unlike splitting, joining isn't `const`, since addresses can't always be compared at
+    /// compile time.
+    ///
+    /// ```should_panic
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap!([1, 2]));
+    /// let [first, second] = reference.each_mut();
+    /// let [first1, first2] = first.split();
+    /// let [second1, second2] = second.split();
+    /// let first = first1.join(second1);
+    /// ```
+    // The other reference must be consumed, so it can't be used after joining
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    #[must_use]
+    pub fn join(self, other: Self) -> Ref<T, B, { parent_level(LEVEL) }> {
+        assert!(
+            same_place(self.ptr, other.ptr),
+            "joined references point to different places"
+        );
         // SAFETY: if these `Ref`s were created safely, calling `::new()` with the same parameters is
         // safe, since we're joining two `Ref`s of the same type while decreasing level by one
-        unsafe { Ref::new(self.ptr, self.brand) }
+        unsafe { Ref::new(self.ptr, self.into_brand()) }
+    }
+
+    /// Join two references of the same level in the form returned by [`.split()`](Ref::split)
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap!(5));
+    /// let reference = Ref::join_array(reference.split());
+    /// let owned = reference.reconstruct(husk);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn join_array([first, second]: [Self; 2]) -> Ref<T, B, { parent_level(LEVEL) }> {
+        first.join(second)
+    }
+
+    /// Narrow this reference to a part of the value, keeping its brand and level. Usually
+    /// [`project!`](crate::project!) should be used instead.
+    ///
+    /// # Safety
+    /// `f` must return a pointer to a valid `U` within the value it's given, e.g. to its field. The
+    /// resulting reference must not be used to reconstruct an owned value unless it points to the
+    /// whole original value again.
+    #[inline]
+    pub unsafe fn map_unchecked<U>(self, f: impl FnOnce(*mut T) -> *mut U) -> Ref<U, B, LEVEL>
+    where
+        U: ?Sized,
+    {
+        // SAFETY: the pointer is valid, as guaranteed by the caller
+        let ptr = unsafe { NonNull::new_unchecked(f(self.ptr.as_ptr())) };
+        // SAFETY: the new pointer is covered by the same brand and level, and doesn't allow
+        // reconstruction, as guaranteed by the caller
+        unsafe { Ref::new(ptr, self.into_brand()) }
+    }
+
+    /// Reinterpret the value as `U`, keeping the brand and level, so the reference can still be
+    /// joined with others of the same type and reconstructed after casting it back. With the
+    /// `bytemuck` feature, [`.peel()`](Ref::peel) and [`.wrap()`](Ref::wrap) do this safely for
+    /// transparent wrappers.
+    ///
+    /// # Safety
+    /// `U` must have the same size as `T` and no stricter alignment, and the value must be a valid
+    /// `U`. For mutable references, every `U` written through the reference must also be a valid
+    /// `T`.
+    #[inline]
+    pub unsafe fn cast_unchecked<U>(self) -> Ref<U, B, LEVEL> {
+        // SAFETY: the cast pointer covers the whole value, and `U` is compatible with `T`, as
+        // guaranteed by the caller
+        unsafe { self.map_unchecked(<*mut T>::cast) }
+    }
+
+    /// An implementation detail of the [`project!`](crate::project!) macro
+    ///
+    /// # Safety
+    /// `field` must only project the pointer it's given to a field.
+    ///
+    /// # Panics
+    /// Panics if the field is reached through [`Deref`] into some other memory, or isn't aligned.
+    #[doc(hidden)]
+    pub unsafe fn project_field<U>(
+        self,
+        field: impl FnOnce(*const T) -> *const U,
+    ) -> Ref<U, B, LEVEL> {
+        let base = self.ptr.as_ptr();
+        let field = field(base.cast_const());
+        let offset = field.addr().wrapping_sub(base.addr());
+        // SAFETY: every `Ref` allows shared access
+        let size = size_of_val(unsafe { self.ptr.as_ref() });
+        // Field access could go through `Deref`, which may point anywhere
+        assert!(
+            offset <= size && size - offset >= size_of::<U>() && field.is_aligned(),
+            "projected field is not a part of the value"
+        );
+        // SAFETY: the field lies within the value and is aligned. Pointer is recomputed from the
+        // original one to keep its provenance, since `Deref` produces a shared reference
+        unsafe { self.map_unchecked(|ptr| ptr.byte_add(offset).cast::<U>()) }
+    }
+
+    /// An implementation detail of `#[derive(Split)]`: whether this reference points to `ptr`
+    #[doc(hidden)]
+    pub fn is_at(&self, ptr: *const T) -> bool {
+        ptr::addr_eq(self.ptr.as_ptr(), ptr)
+    }
+
+    /// Split this reference into `N` immutable references at once. `N` must be a power of two,
+    /// and level is incremented by `log2(N)`, same as splitting with [`.split()`](Ref::split)
+    /// repeatedly.
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap!(5));
+    /// let refs = reference.split_n::<4>();
+    /// assert!(refs.iter().all(|r| **r == 5));
+    /// let owned = Ref::join_n(refs).reconstruct(husk);
+    /// ```
+    ///
+    /// References obtained this way can only become mutable again when all of them are joined:
+    /// ```compile_fail
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap!(5));
+    /// let [ref1, ref2, ref3, ref4] = reference.split_n::<4>();
+    /// let owned = ref1.join(ref2).reconstruct(husk);
+    /// ```
+    /// ```compile_fail
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap!(5));
+    /// let [ref1, ref2, ref3, ref4] = reference.split_n::<4>();
+    /// let owned = Ref::join_n([ref1, ref2]).reconstruct(husk);
+    /// ```
+    /// Other numbers of references can't be tracked by levels:
+    /// ```compile_fail
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap!(5));
+    /// let refs = reference.split_n::<3>();
+    /// ```
+    #[must_use]
+    pub fn split_n<const N: usize>(self) -> [Ref<T, B, { LEVEL + N.ilog2() as usize }>; N] {
+        let () = PowerOfTwo::<N>::ASSERT;
+        let ptr = self.ptr;
+        let mut brand = Some(self.into_brand());
+        array::from_fn(|_| {
+            let Some(current) = brand.take() else {
+                unreachable!()
+            };
+            // SAFETY: we're using `.duplicate()` to split a reference
+            let (current, next) = unsafe { current.duplicate() };
+            brand = Some(next);
+            // SAFETY: if this `Ref` was created safely, calling `::new()` with the same parameters
+            // is safe, since we're splitting `Ref` into `N` while increasing level by `log2(N)`
+            unsafe { Ref::new(ptr, current) }
+        })
+    }
+
+    /// Join `N` references of the same level, decrementing level by `log2(N)`. Inverse of
+    /// [`.split_n()`](Ref::split_n).
+    ///
+    /// # Panics
+    /// Panics if the references point to different places, same as [`.join()`](Ref::join).
+    #[must_use]
+    pub fn join_n<const N: usize>(refs: [Self; N]) -> Ref<T, B, { LEVEL - N.ilog2() as usize }> {
+        let () = PowerOfTwo::<N>::ASSERT;
+        let mut refs = refs.into_iter();
+        let Some(first) = refs.next() else {
+            unreachable!()
+        };
+        assert!(
+            refs.all(|other| same_place(first.ptr, other.ptr)),
+            "joined references point to different places"
+        );
+        // SAFETY: if these `Ref`s were created safely, calling `::new()` with the same parameters
+        // is safe, since we're joining `N` `Ref`s of the same type while decreasing level by
+        // `log2(N)`
+        unsafe { Ref::new(first.ptr, first.into_brand()) }
+    }
+}
+
+impl<T, B, const LEVEL: usize> Ref<T, B, LEVEL>
+where
+    T: Clone,
+    B: IsBrand,
+{
+    /// Clone the value behind this reference
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap!(vec![1, 2]));
+    /// let refs = reference.split_n::<4>();
+    /// let vecs: Vec<Vec<i32>> = refs.iter().map(Ref::clone_inner).collect();
+    /// assert_eq!(vecs, [[1, 2], [1, 2], [1, 2], [1, 2]]);
+    /// ```
+    #[must_use]
+    pub fn clone_inner(&self) -> T {
+        (**self).clone()
+    }
+}
+
+impl<T, B, const LEVEL: usize> Ref<T, B, LEVEL>
+where
+    T: Copy,
+    B: IsBrand,
+{
+    /// Copy the value behind this reference, like [`Cell::get`](std::cell::Cell::get)
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap!(5));
+    /// let [ref1, ref2] = reference.split();
+    /// assert_eq!(ref1.get() + ref2.get(), 10);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn get(&self) -> T {
+        **self
     }
 }
 
 impl<T, B> RefMut<T, B>
 where
+    T: ?Sized,
     B: IsBrand,
 {
+    /// Obtain a plain mutable reference, same as [`DerefMut`]. Like with
+    /// [`.as_ref()`](Ref::as_ref), it can only be held until this `RefMut` is consumed.
+    ///
+    /// There's no way to obtain a shared and a mutable reference to the same value at once, since
+    /// they would alias. Use [`.split_fields()`](Ref::split_fields) to borrow disjoint parts of the
+    /// value instead.
+    // Unlike `AsMut`, which could be implemented for many targets, this is never ambiguous
+    #[allow(clippy::should_implement_trait)]
+    #[inline]
+    #[must_use]
+    pub const fn as_mut(&mut self) -> &mut T {
+        // SAFETY: we're the only reference pointing to `.ptr`
+        unsafe { self.ptr.as_mut() }
+    }
+
+    /// Temporarily borrow this reference as a new mutable reference, which can be passed to
+    /// functions by value. Like with `&mut` references, this one can be used again once the
+    /// reborrowed one and all references obtained from it are gone.
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::{*, brand::IsBrand};
+    /// fn push_twice(reference: RefMut<Vec<i32>, impl IsBrand>, value: i32) {
+    ///     let [ref1, ref2] = reference.split();
+    ///     let len = ref1.len();
+    ///     let mut reference = ref1.join(ref2);
+    ///     reference.push(value);
+    ///     reference.push(value + len as i32);
+    /// }
+    ///
+    /// let (husk, mut reference) = borrow!(heap!(vec![]));
+    /// push_twice(reference.reborrow(), 1);
+    /// push_twice(reference.reborrow(), 2);
+    /// assert_eq!(reference.reconstruct(husk).into_inner(), [1, 1, 2, 4]);
+    /// ```
+    ///
+    /// The reborrowed reference can't outlive the original one:
+    /// ```compile_fail
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, mut reference) = borrow!(heap!(vec![1]));
+    /// let reborrowed = reference.reborrow();
+    /// let owned = reference.reconstruct(husk);
+    /// assert_eq!(*reborrowed, [1]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn reborrow(&mut self) -> RefMut<T, Reborrow<'_, B>> {
+        // SAFETY: this reference is mutably borrowed while the new one and everything derived from
+        // it exists, since its brand carries the lifetime of the borrow
+        unsafe { Ref::new(self.ptr, Reborrow::new()) }
+    }
+
     /// Join this reference with [`Husk`], reconstructing the owned value
-    pub fn reconstruct<Kind>(self, husk: Husk<T, B, Kind>) -> Owned<T, Kind>
+    #[inline]
+    pub fn reconstruct<U, Kind>(self, husk: Husk<U, B, Kind>) -> Owned<U, Kind>
     where
-        Kind: OwnershipKind<T>,
+        U: ?Sized,
+        Kind: OwnershipKind<U, Pointee = T>,
     {
         // We destroyed the last reference...
         let ptr = self.ptr;
         // SAFETY: ...so we're now allowed to reconstruct the owned value
         unsafe { Owned::from_inner(Kind::join(husk.into_inner(), ptr)) }
     }
+
+    /// Join this reference with [`Husk`] and move the value out, like
+    /// `.reconstruct(husk).into_inner()`, but without reconstructing the owned value first. For
+    /// [`Heap`] this reads the value and frees the allocation directly.
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// static DROPS: AtomicUsize = AtomicUsize::new(0);
+    ///
+    /// struct Counted(u32);
+    /// impl Drop for Counted {
+    ///     fn drop(&mut self) {
+    ///         DROPS.fetch_add(1, Ordering::Relaxed);
+    ///     }
+    /// }
+    ///
+    /// let (husk, mut reference) = borrow!(heap!(Counted(1)));
+    /// reference.0 += 1;
+    /// let value = reference.take_value(husk);
+    /// assert_eq!(value.0, 2);
+    /// assert_eq!(DROPS.load(Ordering::Relaxed), 0);
+    /// drop(value);
+    /// assert_eq!(DROPS.load(Ordering::Relaxed), 1);
+    /// ```
+    #[inline]
+    pub fn take_value<U, Kind>(self, husk: Husk<U, B, Kind>) -> U
+    where
+        Kind: MoveOut<U> + OwnershipKind<U, Pointee = T>,
+    {
+        // We destroyed the last reference...
+        let ptr = self.ptr;
+        // SAFETY: ...so we're now allowed to take the owned value
+        unsafe { Kind::take_value(husk.into_inner(), ptr) }
+    }
+
+    /// Join this reference with [`Husk`] and drop the value, like `drop(.reconstruct(husk))`, but
+    /// without reconstructing the owned value first. The storage is released even if the
+    /// destructor panics:
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// struct Explosive;
+    /// impl Drop for Explosive {
+    ///     fn drop(&mut self) {
+    ///         panic!("boom");
+    ///     }
+    /// }
+    ///
+    /// let pool = Pool::new();
+    /// let (husk, reference) = borrow!(pool.alloc(Explosive));
+    /// let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+    ///     reference.discard(husk);
+    /// }));
+    /// assert!(result.is_err());
+    /// // The slot was returned to the pool
+    /// std::mem::forget(pool.alloc(Explosive));
+    /// assert_eq!(pool.capacity(), 1);
+    /// ```
+    pub fn discard<U, Kind>(self, husk: Husk<U, B, Kind>)
+    where
+        U: ?Sized,
+        Kind: OwnershipKind<U, Pointee = T>,
+    {
+        // We destroyed the last reference...
+        let ptr = self.ptr;
+        // SAFETY: ...so we're now allowed to drop the owned value
+        unsafe { Kind::discard(husk.into_inner(), ptr) };
+    }
+}
+
+impl<T, B, U, Kind> RefMut<T, Owning<B, U, Kind>>
+where
+    T: ?Sized,
+    B: IsBrand,
+    U: ?Sized,
+    Kind: TrivialHusk<U, Pointee = T>,
+{
+    /// Reconstruct the owned value without a husk. Available for references created by
+    /// [`borrow_owning!`](crate::borrow_owning!), since their brand records the ownership kind.
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let reference = borrow_owning!(heap!(vec![1, 2]));
+    /// let [left, right] = reference.split();
+    /// assert_eq!(left.len(), right.len());
+    /// let mut reference = left.join(right);
+    /// reference.push(3);
+    /// let owned: Owned<Vec<i32>, Heap> = reference.into_owned();
+    /// assert_eq!(owned.into_inner(), [1, 2, 3]);
+    /// ```
+    ///
+    /// References obtained with [`borrow!`] still need their husk:
+    /// ```compile_fail
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (_husk, reference) = borrow!(heap!(5));
+    /// let owned = reference.into_owned();
+    /// ```
+    #[must_use]
+    pub fn into_owned(self) -> Owned<U, Kind> {
+        // SAFETY: the brand is only created by consuming a husk of this kind, which is `()`, and we
+        // destroyed the last reference
+        unsafe { Owned::from_inner(Kind::join((), self.ptr)) }
+    }
+}
+
+impl<T, B> RefMut<T, B>
+where
+    B: IsBrand,
+{
+    /// Swap the values behind two mutable references, like [`mem::swap`]
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk1, mut ref1) = borrow!(heap!(1));
+    /// let (husk2, mut ref2) = borrow!(heap!(2));
+    /// ref1.swap(&mut ref2);
+    /// assert_eq!(ref1.reconstruct(husk1).into_inner(), 2);
+    /// assert_eq!(ref2.reconstruct(husk2).into_inner(), 1);
+    /// ```
+    #[inline]
+    pub fn swap<B2>(&mut self, other: &mut RefMut<T, B2>)
+    where
+        B2: IsBrand,
+    {
+        mem::swap(&mut **self, &mut **other);
+    }
+
+    /// Replace the value, dropping the old one
+    #[inline]
+    pub fn set(&mut self, value: T) {
+        **self = value;
+    }
+
+    /// Replace the value, returning the old one, like [`mem::replace`]
+    #[must_use = "if you don't need the old value, use `.set()` instead"]
+    #[inline]
+    pub fn replace(&mut self, value: T) -> T {
+        mem::replace(&mut **self, value)
+    }
+
+    /// Take the value, leaving [`Default::default()`] in its place, like [`mem::take`]
+    #[inline]
+    pub fn take(&mut self) -> T
+    where
+        T: Default,
+    {
+        mem::take(&mut **self)
+    }
+
+    /// Replace the value with the result of `f` applied to it.
+    ///
+    /// If `f` panics, the value is left as [`Default::default()`], so it's never observed in a
+    /// moved-out state:
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// # use std::panic::{self, AssertUnwindSafe};
+    /// let (husk, mut reference) = borrow!(heap!(vec![1]));
+    /// reference.update(|mut vec| {
+    ///     vec.push(2);
+    ///     vec
+    /// });
+    /// assert_eq!(*reference, [1, 2]);
+    /// let result = panic::catch_unwind(AssertUnwindSafe(|| {
+    ///     reference.update(|_| panic!("oops"));
+    /// }));
+    /// assert!(result.is_err());
+    /// assert_eq!(*reference, []);
+    /// ```
+    pub fn update(&mut self, f: impl FnOnce(T) -> T)
+    where
+        T: Default,
+    {
+        let value = self.take();
+        self.set(f(value));
+    }
+}
+
+impl<T, B> RefMut<T, B>
+where
+    T: ?Sized + 'static,
+    B: IsBrand,
+{
+    /// Join this reference with [`Husk`] and leak the value, like [`Box::leak`]
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, mut reference) = borrow!(heap!(vec![1]));
+    /// reference.push(2);
+    /// let leaked: &'static mut Vec<i32> = reference.leak(husk);
+    /// assert_eq!(leaked, &[1, 2]);
+    /// ```
+    #[must_use]
+    pub fn leak(self, husk: Husk<T, B, Heap>) -> &'static mut T {
+        Box::leak(self.reconstruct(husk).into_box())
+    }
+}
+
+impl<T, B, const LEVEL: usize> Ref<T, B, LEVEL>
+where
+    T: ?Sized + 'static,
+    B: IsBrand,
+{
+    /// Join all `2^LEVEL` references of this level with [`Husk`] and leak the value as a shared
+    /// reference
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap!(5));
+    /// let [ref1, ref2] = reference.split();
+    /// let leaked: &'static i32 = Ref::leak_shared([ref1, ref2], husk);
+    /// assert_eq!(*leaked, 5);
+    /// ```
+    ///
+    /// All references must be given:
+    /// ```compile_fail
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap!(5));
+    /// let [ref1, ref2] = reference.split();
+    /// let leaked = Ref::leak_shared([ref1], husk);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the references point to different places, same as [`.join()`](Ref::join).
+    #[must_use]
+    pub fn leak_shared<const N: usize>(refs: [Self; N], husk: Husk<T, B, Heap>) -> &'static T {
+        let () = AllReferences::<LEVEL, N>::ASSERT;
+        let mut refs = refs.into_iter();
+        let Some(first) = refs.next() else {
+            unreachable!()
+        };
+        assert!(
+            refs.all(|other| same_place(first.ptr, other.ptr)),
+            "joined references point to different places"
+        );
+        // SAFETY: all references of the level are joined, so this is the only one of level 0
+        let reference: RefMut<T, B> = unsafe { Ref::new(first.ptr, first.into_brand()) };
+        reference.leak(husk)
+    }
+}
+
+impl<T, B> RefMut<MaybeUninit<T>, B>
+where
+    B: IsBrand,
+{
+    /// Initialize the referenced value, obtaining a reference to it
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap_uninit!([u8; 4096]));
+    /// let reference = reference.write([7; 4096]);
+    /// let owned = reference.reconstruct(husk.into_init());
+    /// assert!(owned.into_inner().iter().all(|&byte| byte == 7));
+    /// ```
+    pub fn write(mut self, value: T) -> RefMut<T, B> {
+        MaybeUninit::write(&mut *self, value);
+        // SAFETY: we've just initialized the value
+        unsafe { self.assume_init() }
+    }
+
+    /// Obtain a reference to the value which was initialized in place
+    ///
+    /// # Safety
+    /// The value must be initialized (see [`MaybeUninit::assume_init`]).
+    #[must_use]
+    pub unsafe fn assume_init(self) -> RefMut<T, B> {
+        // SAFETY: `MaybeUninit<T>` has the same layout as `T`, the value is initialized, and we're
+        // consuming the only level 0 reference while keeping its brand
+        unsafe { Ref::new(self.ptr.cast::<T>(), self.into_brand()) }
+    }
+}
+
+impl<T, B, const LEVEL: usize> Ref<T, B, LEVEL>
+where
+    T: ?Sized + Unpin,
+    B: IsBrand,
+{
+    /// Access the value through [`Pin`], e.g. to pass it to an API taking pinned references
+    #[inline]
+    #[must_use]
+    pub fn as_pin(&self) -> Pin<&T> {
+        Pin::new(self.as_ref())
+    }
+}
+
+impl<T, B> RefMut<T, B>
+where
+    T: ?Sized + Unpin,
+    B: IsBrand,
+{
+    /// Mutably access the value through [`Pin`], e.g. to poll an [`Unpin`] future
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// use std::{
+    ///     future::{self, Future},
+    ///     task::{Context, Poll, Waker},
+    /// };
+    ///
+    /// let (husk, mut reference) = borrow!(heap!(future::ready(5)));
+    /// let mut context = Context::from_waker(Waker::noop());
+    /// assert_eq!(reference.as_pin_mut().poll(&mut context), Poll::Ready(5));
+    /// let owned = reference.reconstruct(husk);
+    /// ```
+    ///
+    /// Pinning values which aren't [`Unpin`] requires them to never move until they're dropped,
+    /// but values of most kinds, like [`Heap`], can be moved out after reconstructing. Such values
+    /// should be created with [`heap_pin!`](crate::heap_pin!) instead, and accessed with
+    /// [`.pinned_mut()`](Ref::pinned_mut):
+    /// ```compile_fail
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, mut reference) = borrow!(heap!(async { 5 }));
+    /// let pinned = reference.as_pin_mut();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_pin_mut(&mut self) -> Pin<&mut T> {
+        Pin::new(self.as_mut())
+    }
+}
+
+impl<T, B> RefMut<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    /// Access the value through a [`Cell`], like [`Cell::from_mut`]. Closures can then share the
+    /// cell to write to the value without splitting the reference.
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, mut reference) = borrow!(heap!(0));
+    /// let total = reference.as_cell();
+    /// let add = |amount| total.set(total.get() + amount);
+    /// add(2);
+    /// add(3);
+    /// assert_eq!(reference.reconstruct(husk).into_inner(), 5);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_cell(&mut self) -> &Cell<T> {
+        Cell::from_mut(self.as_mut())
+    }
+}
+
+impl<B, const LEVEL: usize> Ref<dyn Any, B, LEVEL>
+where
+    B: IsBrand,
+{
+    /// Obtain a plain reference to the value if it's a `T`, see
+    /// [`Owned::downcast`](crate::Owned::downcast)
+    #[must_use]
+    pub fn downcast_ref<T>(&self) -> Option<&T>
+    where
+        T: Any,
+    {
+        self.as_ref().downcast_ref()
+    }
+}
+
+impl<B> RefMut<dyn Any, B>
+where
+    B: IsBrand,
+{
+    /// Obtain a plain mutable reference to the value if it's a `T`
+    #[must_use]
+    pub fn downcast_mut<T>(&mut self) -> Option<&mut T>
+    where
+        T: Any,
+    {
+        self.as_mut().downcast_mut()
+    }
+}
+
+impl<T, B, const LEVEL: usize> Ref<Pinned<T>, B, LEVEL>
+where
+    B: IsBrand,
+{
+    /// Access the pinned value
+    #[must_use]
+    pub fn pinned(&self) -> Pin<&T> {
+        // SAFETY: `Pinned<T>` is only ever a pointee of references into a pinned allocation
+        // holding `T`, which can't be moved out of while it's not `Unpin`
+        unsafe { Pin::new_unchecked(self.ptr.cast::<T>().as_ref()) }
+    }
+}
+
+impl<T, B> RefMut<Pinned<T>, B>
+where
+    B: IsBrand,
+{
+    /// Mutably access the pinned value, e.g. to poll a future in place:
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// use std::{
+    ///     future::Future,
+    ///     task::{Context, Poll, Waker},
+    /// };
+    ///
+    /// let mut ready = false;
+    /// let future = std::future::poll_fn(move |_| {
+    ///     if ready {
+    ///         Poll::Ready(5)
+    ///     } else {
+    ///         ready = true;
+    ///         Poll::Pending
+    ///     }
+    /// });
+    /// // `async` blocks aren't `Unpin`
+    /// let (husk, mut reference) = borrow!(heap_pin!(async { future.await * 2 }));
+    /// let mut context = Context::from_waker(Waker::noop());
+    /// assert_eq!(reference.pinned_mut().poll(&mut context), Poll::Pending);
+    /// assert_eq!(reference.pinned_mut().poll(&mut context), Poll::Ready(10));
+    /// let owned = reference.reconstruct(husk);
+    /// ```
+    #[must_use]
+    pub fn pinned_mut(&mut self) -> Pin<&mut T> {
+        // SAFETY: same as in `.pinned()`, and we're the only reference pointing to `.ptr`
+        unsafe { Pin::new_unchecked(self.ptr.cast::<T>().as_mut()) }
+    }
 }
 
 impl<T, B, const LEVEL: usize> Deref for Ref<T, B, LEVEL>
 where
+    T: ?Sized,
     B: IsBrand,
 {
     type Target = T;
 
+    #[inline]
     fn deref(&self) -> &Self::Target {
-        // SAFETY: only references pointing to `.ptr` currently are non-zero-LEVEL `Ref`s which do
-        // not allow obtaining mutable references (or we are the only zero-LEVEL `Ref` which is
-        // also OK)
-        unsafe { self.ptr.as_ref() }
+        self.as_ref()
     }
 }
 
 impl<T, B> DerefMut for RefMut<T, B>
 where
+    T: ?Sized,
     B: IsBrand,
 {
+    #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
-        // SAFETY: we're the only reference pointing to `.ptr`
-        unsafe { self.ptr.as_mut() }
+        self.as_mut()
     }
 }
 
+/// Indexes the value, so references can be used where [`Index`] is required, e.g. by generic
+/// code. Index expressions like `reference[i]` work through [`Deref`] anyway.
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// use std::{collections::HashMap, ops::Index};
+///
+/// fn first<C: Index<usize, Output = i32>>(container: &C) -> i32 {
+///     container[0]
+/// }
+///
+/// let (husk, mut reference) = borrow!(heap!(vec![1, 2]));
+/// reference[0] = 3;
+/// let second = &reference[1];
+/// assert_eq!(*second, 2);
+/// assert_eq!(first(&reference), 3);
+/// # reference.reconstruct(husk);
+///
+/// let (husk, reference) = borrow!(heap!([1, 2, 3]));
+/// let [left, right] = reference.split();
+/// assert_eq!(first(&left), 1);
+/// assert_eq!(right[1..], [2, 3]);
+/// # left.join(right).reconstruct(husk);
+///
+/// let (husk, reference) = borrow!(heap!(HashMap::from([("key", 1)])));
+/// assert_eq!(reference["key"], 1);
+/// # reference.reconstruct(husk);
+/// ```
+///
+/// Elements borrow the reference, like with [`.as_ref()`](Ref::as_ref):
+/// ```compile_fail
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// let (husk, reference) = borrow!(heap!(vec![1, 2]));
+/// let first = &reference[0];
+/// let owned = reference.reconstruct(husk);
+/// assert_eq!(*first, 1);
+/// ```
+impl<T, I, B, const LEVEL: usize> Index<I> for Ref<T, B, LEVEL>
+where
+    T: ?Sized + Index<I>,
+    B: IsBrand,
+{
+    type Output = T::Output;
+
+    #[inline]
+    fn index(&self, index: I) -> &Self::Output {
+        &self.as_ref()[index]
+    }
+}
+
+impl<T, I, B> IndexMut<I> for RefMut<T, B>
+where
+    T: ?Sized + IndexMut<I>,
+    B: IsBrand,
+{
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.as_mut()[index]
+    }
+}
+
+/// Bounds which a value must satisfy for references to it to be [`UnwindSafe`]. Only shared
+/// references have them, like `&T`.
+#[doc(hidden)]
+pub trait UnwindAccess<T>
+where
+    T: ?Sized,
+{
+}
+
+// Never holds, but reports the same error as moving `&mut T` would
+impl<T> UnwindAccess<T> for Access<true>
+where
+    T: ?Sized,
+    for<'a> &'a mut T: UnwindSafe,
+{
+}
+
+impl<T> UnwindAccess<T> for Access<false> where T: ?Sized + RefUnwindSafe {}
+
+/// Shared references can be moved into [`catch_unwind`](std::panic::catch_unwind) when `&T` could
+/// be, whatever the brand is:
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// use std::panic;
+///
+/// let (husk, reference) = borrow!(heap!(vec![1, 2]));
+/// let [left, right] = reference.split();
+/// let result = panic::catch_unwind(move || {
+///     assert_eq!(left.len(), 3);
+/// });
+/// assert!(result.is_err());
+/// # drop((husk, right));
+/// ```
+///
+/// Mutable references aren't [`UnwindSafe`], same as `&mut T`, since a panic can leave the value
+/// half-modified:
+/// ```compile_fail
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// let (husk, mut reference) = borrow!(heap!(vec![1, 2]));
+/// let mut reborrowed = reference.reborrow();
+/// let result = std::panic::catch_unwind(move || reborrowed.push(3));
+/// ```
+///
+/// If that's fine, wrap the closure in [`AssertUnwindSafe`](std::panic::AssertUnwindSafe). The
+/// value is left as it was when the panic happened, same as with `Box<T>`:
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// use std::panic::{self, AssertUnwindSafe};
+///
+/// let (husk, mut reference) = borrow!(heap!(vec![1, 2]));
+/// let mut reborrowed = reference.reborrow();
+/// let result = panic::catch_unwind(AssertUnwindSafe(move || {
+///     reborrowed.push(3);
+///     panic!("oops");
+/// }));
+/// assert!(result.is_err());
+/// assert_eq!(reference.reconstruct(husk).into_inner(), [1, 2, 3]);
+/// ```
+// Brands don't give access to anything, so only the pointee matters
+impl<T, B, const LEVEL: usize> UnwindSafe for Ref<T, B, LEVEL>
+where
+    T: ?Sized,
+    B: IsBrand,
+    Access<{ LEVEL == 0 }>: UnwindAccess<T>,
+{
+}
+
+// `&Ref` only gives out `&T`, whatever the level is
+impl<T, B, const LEVEL: usize> RefUnwindSafe for Ref<T, B, LEVEL>
+where
+    T: ?Sized + RefUnwindSafe,
+    B: IsBrand,
+{
+}
+
+#[cfg(feature = "unsize")]
+impl<T, U, B, const LEVEL: usize> CoerceUnsized<Ref<U, B, LEVEL>> for Ref<T, B, LEVEL>
+where
+    T: ?Sized + Unsize<U>,
+    U: ?Sized,
+    B: IsBrand,
+{
+}
+
+#[cfg(feature = "unsize")]
+impl<T, B, const LEVEL: usize> Ref<T, B, LEVEL>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    /// Convert this reference into a reference to an unsized type, e.g. a trait object, keeping
+    /// the brand and level. References also coerce implicitly, so this is only needed when the
+    /// target type can't be inferred.
+    ///
+    /// The converted reference can be split and joined, but can't be used to reconstruct the
+    /// value, since the husk expects the original type.
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// use std::io::Write;
+    ///
+    /// let (_husk, reference) = borrow!(heap!(Vec::new()));
+    /// let mut writer: RefMut<dyn Write, _> = reference;
+    /// writer.write_all(b"implicit").unwrap();
+    ///
+    /// let (_husk, reference) = borrow!(heap!(5));
+    /// let [left, right] = reference.split();
+    /// let left = left.unsize::<dyn std::fmt::Display>();
+    /// let right = right.unsize::<dyn std::fmt::Display>();
+    /// assert_eq!(left.to_string(), "5");
+    /// let reference = left.join(right);
+    /// ```
+    #[must_use]
+    pub fn unsize<U>(self) -> Ref<U, B, LEVEL>
+    where
+        T: Unsize<U>,
+        U: ?Sized,
+    {
+        self
+    }
+}
+
+/// Formats the value, like [`Box`] does. The brand doesn't need to implement [`Debug`](fmt::Debug).
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// let (husk, reference) = borrow!(heap!(vec![1, 2]));
+/// assert_eq!(format!("{reference:?}"), "[1, 2]");
+/// # reference.reconstruct(husk);
+/// ```
+///
+/// Values which don't implement [`Debug`](fmt::Debug) can be described by their types and the
+/// level of the reference with [`.describe()`](Ref::describe):
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// struct Opaque;
+///
+/// let (husk, reference) = borrow!(heap!(Opaque));
+/// let [left, right] = reference.split();
+/// assert!(format!("{:?}", left.describe()).starts_with("RefDescription { level: 1, "));
+/// # left.join(right).reconstruct(husk);
+/// ```
+impl<T, B, const LEVEL: usize> fmt::Debug for Ref<T, B, LEVEL>
+where
+    T: ?Sized + fmt::Debug,
+    B: IsBrand,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_ref(), f)
+    }
+}
+
+/// Formats the value, so references can be used in [`format!`] directly
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// let (husk, reference) = borrow!(heap!(String::from("world")));
+/// let [left, right] = reference.split();
+/// assert_eq!(format!("hello, {left}"), "hello, world");
+/// # left.join(right).reconstruct(husk);
+/// ```
+impl<T, B, const LEVEL: usize> fmt::Display for Ref<T, B, LEVEL>
+where
+    T: ?Sized + fmt::Display,
+    B: IsBrand,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_ref(), f)
+    }
+}
+
+/// Forwards to the error, so references can be boxed into `Box<dyn Error>` or inspected by error
+/// reporters
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// use std::{error::Error, fmt, io};
+///
+/// #[derive(Debug)]
+/// struct ConfigError(io::Error);
+///
+/// impl fmt::Display for ConfigError {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         f.write_str("failed to read config")
+///     }
+/// }
+///
+/// impl Error for ConfigError {
+///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+///         Some(&self.0)
+///     }
+/// }
+///
+/// fn report(error: &dyn Error) -> String {
+///     let mut report = error.to_string();
+///     let mut source = error.source();
+///     while let Some(error) = source {
+///         report += &format!(": {error}");
+///         source = error.source();
+///     }
+///     report
+/// }
+///
+/// let (husk, reference) = borrow!(heap!(ConfigError(io::Error::other("disk on fire"))));
+/// let [left, right] = reference.split();
+/// assert_eq!(report(&left), "failed to read config: disk on fire");
+/// let boxed: Box<dyn Error> = right.into();
+/// assert_eq!(report(&*boxed), "failed to read config: disk on fire");
+/// # let _ = husk;
+/// ```
+impl<T, B, const LEVEL: usize> Error for Ref<T, B, LEVEL>
+where
+    T: ?Sized + Error,
+    B: IsBrand,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.as_ref().source()
+    }
+}
+
+/// Writes to the value, so mutable references can be given to `write!` and to functions taking
+/// `&mut dyn fmt::Write`
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// use std::fmt::Write;
+///
+/// fn list(out: &mut dyn Write, items: &[i32]) -> std::fmt::Result {
+///     for item in items {
+///         write!(out, "{item};")?;
+///     }
+///     out.write_char('.')
+/// }
+///
+/// let (husk, mut text) = borrow!(heap!(String::new()));
+/// write!(text, "items: ")?;
+/// list(&mut text, &[1, 2])?;
+/// assert_eq!(text.reconstruct(husk).into_inner(), "items: 1;2;.");
+/// # Ok::<(), std::fmt::Error>(())
+/// ```
+impl<T, B> fmt::Write for RefMut<T, B>
+where
+    T: ?Sized + fmt::Write,
+    B: IsBrand,
+{
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.as_mut().write_str(s)
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.as_mut().write_char(c)
+    }
+
+    #[inline]
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> fmt::Result {
+        self.as_mut().write_fmt(args)
+    }
+}
+
+impl<T, B, const LEVEL: usize> fmt::Pointer for Ref<T, B, LEVEL>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.ptr, f)
+    }
+}
+
+/// Whether two references point to the same value, i.e. have the same address and size. Works
+/// across different brands and levels.
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// let (husk1, reference1) = borrow!(heap!(5));
+/// let (husk2, reference2) = borrow!(heap!(5));
+/// let [left, right] = reference1.split();
+/// assert!(ptr_eq(&left, &right));
+/// assert!(!ptr_eq(&left, &reference2));
+/// assert_eq!(left.addr(), right.addr());
+/// assert_eq!(format!("{left:p}"), format!("{:p}", left.as_ptr()));
+/// # left.join(right).reconstruct(husk1);
+/// # reference2.reconstruct(husk2);
+/// ```
+///
+/// Distinct zero-sized values may be placed at the same address, so they may compare as equal:
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// let (husk1, reference1) = borrow!(heap!(()));
+/// let (husk2, reference2) = borrow!(heap!(()));
+/// // `Box` doesn't allocate zero-sized values
+/// assert!(ptr_eq(&reference1, &reference2));
+/// # reference1.reconstruct(husk1);
+/// # reference2.reconstruct(husk2);
+/// ```
+#[must_use]
+pub fn ptr_eq<T, B1, B2, const LEVEL1: usize, const LEVEL2: usize>(
+    first: &Ref<T, B1, LEVEL1>,
+    second: &Ref<T, B2, LEVEL2>,
+) -> bool
+where
+    T: ?Sized,
+    B1: IsBrand,
+    B2: IsBrand,
+{
+    same_place(first.ptr, second.ptr)
+}
+
 /// Split an [`Owned`] value into [`Husk`] and [`Ref`]
 #[macro_export]
 macro_rules! borrow {
@@ -111,3 +1564,156 @@ macro_rules! borrow {
         (husk, reference)
     }};
 }
+
+/// Like [`borrow!`], but evaluates to `Err(owned)` instead of panicking if the [`Owned`] value
+/// can't be split at the moment (e.g. a shared [`RcKind`](crate::RcKind))
+#[macro_export]
+macro_rules! try_borrow {
+    ($owned:expr) => {{
+        let owned = $owned;
+        let brand = $crate::brand::brand!();
+        // SAFETY: we're using `.duplicate()` to obtain husk and ref from the owned object
+        let (husk_brand, ref_brand) = unsafe { $crate::brand::IsBrand::duplicate(brand) };
+        // SAFETY: we will use the same brand to construct reference
+        match unsafe { $crate::Owned::try_split(owned, husk_brand) } {
+            ::core::result::Result::Ok((husk, ptr)) => {
+                // SAFETY: `ptr` is owned by a provided `Owned` value and is obtained by calling
+                // `Owned::try_split` with the same `brand`
+                let reference = unsafe { $crate::Ref::<_, _, 0>::new(ptr, ref_brand) };
+                ::core::result::Result::Ok((husk, reference))
+            }
+            ::core::result::Result::Err(owned) => ::core::result::Result::Err(owned),
+        }
+    }};
+}
+
+/// Like [`borrow!`], but only gives a [`RefMut`], which can be turned back into the [`Owned`]
+/// value with [`.into_owned()`](Ref::into_owned). Works for ownership kinds with a trivial husk,
+/// like [`Heap`](crate::Heap).
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// let mut reference = borrow_owning!(heap!(String::from("hello")));
+/// reference.push_str(", world");
+/// assert_eq!(reference.into_owned().into_inner(), "hello, world");
+/// ```
+///
+/// Kinds which need a husk to reconstruct the value are rejected:
+/// ```compile_fail
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// let reference = borrow_owning!(Owned::wrap_vec(vec![1, 2, 3]));
+/// ```
+#[macro_export]
+macro_rules! borrow_owning {
+    ($owned:expr) => {{
+        let owned = $owned;
+        let brand = $crate::brand::brand!();
+        // SAFETY: we're using `.duplicate()` to obtain husk and ref from the owned object
+        let (husk_brand, ref_brand) = unsafe { $crate::brand::IsBrand::duplicate(brand) };
+        // SAFETY: we will use the same brand to construct reference
+        let (husk, ptr) = unsafe { $crate::Owned::split(owned, husk_brand) };
+        // SAFETY: `ref_brand` is the duplicate of the husk brand, and the husk is consumed
+        let owning = unsafe { $crate::brand::Owning::new(ref_brand, husk) };
+        // SAFETY: `ptr` is owned by a provided `Owned` value and is obtained by calling
+        // `Owned::split` with the same `brand`, which is now recorded in `owning`
+        unsafe { $crate::Ref::<_, _, 0>::new(ptr, owning) }
+    }};
+}
+
+/// Join a tree of references back together. Arrays of references are joined with
+/// [`Ref::join_n`], and may be nested to mirror the way they were split:
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// let (husk, reference) = borrow!(heap!(5));
+/// let [left, right] = reference.split();
+/// let [left1, left2] = left.split();
+/// let [right1, right2, right3, right4] = right.split_n::<4>();
+/// let reference = join_all!([[left1, left2], [right1, right2, right3, right4]]);
+/// let owned = reference.reconstruct(husk);
+/// ```
+/// Trees which don't match the splits produce a level mismatch:
+/// ```compile_fail
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// let (husk, reference) = borrow!(heap!(5));
+/// let [left, right] = reference.split();
+/// let [left1, left2] = left.split();
+/// let reference = join_all!([left1, left2, right]);
+/// ```
+/// Leaves may be arbitrary expressions wrapped in parentheses.
+#[macro_export]
+macro_rules! join_all {
+    ([$($inner:tt),+ $(,)?]) => {
+        // Subtrees are bound to variables first, since nested calls make the compiler crash when
+        // evaluating levels
+        $crate::Ref::join_n([$({
+            let joined = $crate::join_all!($inner);
+            joined
+        }),+])
+    };
+    ($leaf:expr) => {
+        $leaf
+    };
+}
+
+/// Narrow a [`Ref`] to a field of the referenced value, keeping its brand and level
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// struct Point {
+///     x: i32,
+///     y: (i32, i32),
+/// }
+///
+/// let (husk, reference) = borrow!(heap!(Point { x: 1, y: (2, 3) }));
+/// let [ref1, ref2] = reference.split();
+/// let x = project!(ref1 => .x);
+/// let y = project!(ref2 => .y.1);
+/// assert_eq!((*x, *y), (1, 3));
+/// ```
+/// Projection of a shared reference is shared:
+/// ```compile_fail
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// # struct Point { x: i32 }
+/// let (husk, reference) = borrow!(heap!(Point { x: 1 }));
+/// let [ref1, ref2] = reference.split();
+/// let mut x = project!(ref1 => .x);
+/// *x += 1;
+/// ```
+/// Projected reference can't be used to reconstruct the value:
+/// ```compile_fail
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// # struct Point { x: i32 }
+/// let (husk, reference) = borrow!(heap!(Point { x: 1 }));
+/// let x = project!(reference => .x);
+/// let owned = x.reconstruct(husk);
+/// ```
+///
+/// # Panics
+/// Panics if the field is reached through [`Deref`](std::ops::Deref), e.g. when projecting a
+/// `Ref<Box<Point>, ..>`:
+/// ```should_panic
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// # struct Point { x: i32 }
+/// let (husk, reference) = borrow!(heap!(Box::new(Point { x: 1 })));
+/// let x = project!(reference => .x);
+/// ```
+#[macro_export]
+macro_rules! project {
+    ($reference:expr => $(.$field:tt)+) => {{
+        let reference = $reference;
+        // SAFETY: we're only projecting to a field
+        unsafe {
+            $crate::Ref::project_field(reference, |ptr| {
+                ::core::ptr::addr_of!((*ptr)$(.$field)+)
+            })
+        }
+    }};
+}
@@ -0,0 +1,82 @@
+//! Using references with [`tokio::io`]
+
+use std::{
+    io,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+use super::RefMut;
+use crate::brand::IsBrand;
+
+impl<T, B> RefMut<T, B>
+where
+    T: ?Sized + Unpin,
+    B: IsBrand,
+{
+    /// Pin the pointee through a pinned reference to it
+    fn pin_pointee(self: Pin<&mut Self>) -> Pin<&mut T> {
+        // SAFETY: the reference is never moved out of, and pinning it doesn't pin the pointee
+        unsafe { self.get_unchecked_mut() }.as_pin_mut()
+    }
+}
+
+/// Reads from the value, so mutable references can be given to functions like
+/// [`tokio::io::copy`]
+impl<T, B> AsyncRead for RefMut<T, B>
+where
+    T: ?Sized + AsyncRead + Unpin,
+    B: IsBrand,
+{
+    #[inline]
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        self.pin_pointee().poll_read(cx, buf)
+    }
+}
+
+/// Writes to the value, so mutable references can be given to functions like
+/// [`tokio::io::copy`]
+impl<T, B> AsyncWrite for RefMut<T, B>
+where
+    T: ?Sized + AsyncWrite + Unpin,
+    B: IsBrand,
+{
+    #[inline]
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.pin_pointee().poll_write(cx, buf)
+    }
+
+    #[inline]
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.pin_pointee().poll_flush(cx)
+    }
+
+    #[inline]
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.pin_pointee().poll_shutdown(cx)
+    }
+
+    #[inline]
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        self.pin_pointee().poll_write_vectored(cx, bufs)
+    }
+
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        self.as_ref().is_write_vectored()
+    }
+}
@@ -0,0 +1,158 @@
+//! Grouping references with the same brand
+
+use std::ops::{Deref, DerefMut};
+
+use super::Ref;
+use crate::brand::IsBrand;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Tuples of [`Ref`]s which all have the same brand
+pub trait SameBrand: sealed::Sealed {
+    /// The brand of all the references
+    type Brand: IsBrand;
+}
+
+macro_rules! impl_same_brand {
+    ($($ty:ident $level:ident),+) => {
+        impl<B, $($ty, const $level: usize),+> sealed::Sealed for ($(Ref<$ty, B, $level>,)+)
+        where
+            B: IsBrand,
+            $($ty: ?Sized,)+
+        {
+        }
+
+        impl<B, $($ty, const $level: usize),+> SameBrand for ($(Ref<$ty, B, $level>,)+)
+        where
+            B: IsBrand,
+            $($ty: ?Sized,)+
+        {
+            type Brand = B;
+        }
+    };
+}
+
+impl_same_brand!(T1 L1);
+impl_same_brand!(T1 L1, T2 L2);
+impl_same_brand!(T1 L1, T2 L2, T3 L3);
+impl_same_brand!(T1 L1, T2 L2, T3 L3, T4 L4);
+impl_same_brand!(T1 L1, T2 L2, T3 L3, T4 L4, T5 L5);
+impl_same_brand!(T1 L1, T2 L2, T3 L3, T4 L4, T5 L5, T6 L6);
+impl_same_brand!(T1 L1, T2 L2, T3 L3, T4 L4, T5 L5, T6 L6, T7 L7);
+impl_same_brand!(T1 L1, T2 L2, T3 L3, T4 L4, T5 L5, T6 L6, T7 L7, T8 L8);
+
+/// A tuple of up to 8 references with the same brand, created by [`Ref::pair_with`] or
+/// [`bundle!`](crate::bundle!). The references are accessed as fields of the tuple, and given
+/// back by [`.unbundle()`](RefBundle::unbundle).
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// use nolife::brand::IsBrand;
+///
+/// struct Config {
+///     name: String,
+///     retries: u32,
+/// }
+///
+/// fn describe<B: IsBrand>(bundle: &RefBundle<(Ref<String, B, 1>, Ref<u32, B, 1>)>) -> String {
+///     format!("{} ({} retries)", *bundle.0, *bundle.1)
+/// }
+///
+/// let config = heap!(Config { name: String::from("server"), retries: 3 });
+/// let (husk, reference) = borrow!(config);
+/// let [ref1, ref2] = reference.split();
+/// let bundle = project!(ref1 => .name).pair_with(project!(ref2 => .retries));
+/// assert_eq!(describe(&bundle), "server (3 retries)");
+/// let (name, retries) = bundle.unbundle();
+/// ```
+///
+/// All the references must have the same brand:
+/// ```compile_fail
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// let (husk1, ref1) = borrow!(heap!(1));
+/// let (husk2, ref2) = borrow!(heap!(2));
+/// let bundle = ref1.pair_with(ref2);
+/// ```
+pub struct RefBundle<R>(R)
+where
+    R: SameBrand;
+
+impl<R> RefBundle<R>
+where
+    R: SameBrand,
+{
+    /// Bundle a tuple of references with the same brand, see also [`bundle!`](crate::bundle!)
+    #[inline]
+    pub const fn new(refs: R) -> Self {
+        Self(refs)
+    }
+
+    /// Give the bundled references back
+    #[inline]
+    pub fn unbundle(self) -> R {
+        self.0
+    }
+}
+
+impl<R> Deref for RefBundle<R>
+where
+    R: SameBrand,
+{
+    type Target = R;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<R> DerefMut for RefBundle<R>
+where
+    R: SameBrand,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T, B, const LEVEL: usize> Ref<T, B, LEVEL>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    /// Bundle this reference with another one with the same brand, see [`RefBundle`]
+    #[inline]
+    #[must_use]
+    pub fn pair_with<U, const OTHER_LEVEL: usize>(
+        self,
+        other: Ref<U, B, OTHER_LEVEL>,
+    ) -> RefBundle<(Self, Ref<U, B, OTHER_LEVEL>)>
+    where
+        U: ?Sized,
+    {
+        RefBundle::new((self, other))
+    }
+}
+
+/// Bundle up to 8 references with the same brand into a [`RefBundle`]
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// let (husk, reference) = borrow!(heap!(2));
+/// let [a, b] = reference.split();
+/// let [b, c] = b.split();
+/// let bundle = bundle!(a, b, c);
+/// assert_eq!(*bundle.0 + *bundle.1 + *bundle.2, 6);
+/// let (a, b, c) = bundle.unbundle();
+/// let owned = a.join(b.join(c)).reconstruct(husk);
+/// ```
+#[macro_export]
+macro_rules! bundle {
+    ($($reference:expr),+ $(,)?) => {
+        $crate::RefBundle::new(($($reference,)+))
+    };
+}
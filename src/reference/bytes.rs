@@ -0,0 +1,134 @@
+//! Viewing references to [`Pod`] values as references to their bytes
+
+use std::{
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+use bytemuck::Pod;
+
+use super::RefMut;
+use crate::brand::IsBrand;
+
+/// Bytes of a `T`, aligned like `T`, so they can be viewed as `T` again. `N` is always
+/// `size_of::<T>()`. Created by [`.bytes_of_mut()`](crate::Ref::bytes_of_mut), or with [`Bytes::new`] to
+/// read a `T` out of raw bytes.
+#[repr(C)]
+pub struct Bytes<T, const N: usize> {
+    _align: [T; 0],
+    bytes: [u8; N],
+}
+
+impl<T, const N: usize> Bytes<T, N> {
+    /// Wrap raw bytes, e.g. received from the network
+    #[must_use]
+    pub const fn new(bytes: [u8; N]) -> Self {
+        Self { _align: [], bytes }
+    }
+
+    /// Unwrap the raw bytes
+    #[must_use]
+    pub const fn into_array(self) -> [u8; N] {
+        self.bytes
+    }
+}
+
+impl<T, const N: usize> Deref for Bytes<T, N> {
+    type Target = [u8; N];
+
+    fn deref(&self) -> &Self::Target {
+        &self.bytes
+    }
+}
+
+impl<T, const N: usize> DerefMut for Bytes<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.bytes
+    }
+}
+
+/// Compile-time check that `N` bytes make up a `T`
+struct SameSize<T, const N: usize>(PhantomData<T>);
+
+impl<T, const N: usize> SameSize<T, N> {
+    const ASSERT: () = assert!(
+        size_of::<T>() == N,
+        "number of bytes must be the size of the type"
+    );
+}
+
+impl<T, B> RefMut<T, B>
+where
+    T: Pod,
+    B: IsBrand,
+{
+    /// View the value as its bytes, keeping the brand. The bytes keep the alignment of `T`, so
+    /// they can be viewed as `T` again with [`.from_bytes()`](crate::Ref::from_bytes) to reconstruct the
+    /// value.
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// use bytemuck::{Pod, Zeroable};
+    ///
+    /// #[derive(Clone, Copy)]
+    /// #[repr(C)]
+    /// struct Header {
+    ///     magic: u32,
+    ///     len: u32,
+    /// }
+    /// // SAFETY: `Header` is `repr(C)` and consists of integers without padding
+    /// unsafe impl Zeroable for Header {}
+    /// unsafe impl Pod for Header {}
+    ///
+    /// let (husk, reference) = borrow!(heap!(Header { magic: 0xCAFE, len: 4 }));
+    /// let mut bytes = reference.bytes_of_mut();
+    /// assert_eq!(bytes[..4], 0xCAFE_u32.to_ne_bytes());
+    /// bytes[4..].copy_from_slice(&8_u32.to_ne_bytes());
+    /// let header = bytes.from_bytes().reconstruct(husk).into_inner();
+    /// assert_eq!(header.len, 8);
+    ///
+    /// // Raw bytes can be viewed as `Header` too
+    /// let mut wire = [0; 8];
+    /// wire[..4].copy_from_slice(&0xBEEF_u32.to_ne_bytes());
+    /// let (husk, reference) = borrow!(heap!(Bytes::<Header, 8>::new(wire)));
+    /// let header = reference.from_bytes();
+    /// assert_eq!(header.magic, 0xBEEF);
+    /// # header.bytes_of_mut().reconstruct(husk);
+    /// ```
+    ///
+    /// Bytes of the wrong size can't be viewed as `T`:
+    /// ```compile_fail
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap!(Bytes::<u64, 4>::new([0; 4])));
+    /// let value: RefMut<u64, _> = reference.from_bytes();
+    /// ```
+    #[must_use]
+    pub fn bytes_of_mut(self) -> RefMut<Bytes<T, { size_of::<T>() }>, B>
+    where
+        [(); size_of::<T>()]:,
+    {
+        // SAFETY: `Bytes<T, size_of::<T>()>` has the size and alignment of `T`, and `T` is `Pod`,
+        // so its bytes are initialized and any bytes written are a valid `T`
+        unsafe { self.cast_unchecked() }
+    }
+}
+
+impl<T, B, const N: usize> RefMut<Bytes<T, N>, B>
+where
+    B: IsBrand,
+{
+    /// View the bytes as a `T`, keeping the brand. Reverses
+    /// [`.bytes_of_mut()`](crate::Ref::bytes_of_mut).
+    #[must_use]
+    pub fn from_bytes(self) -> RefMut<T, B>
+    where
+        T: Pod,
+    {
+        let () = SameSize::<T, N>::ASSERT;
+        // SAFETY: `Bytes<T, N>` is aligned like `T` and has its size, as checked above, and `T`
+        // is `Pod`, so any bytes are a valid `T`
+        unsafe { self.cast_unchecked() }
+    }
+}
@@ -0,0 +1,69 @@
+//! Calling closures through references
+//!
+//! Mutable references to closures can be called like `&mut F` can. Calling one by value consumes
+//! it, so [`.reborrow()`](super::Ref::reborrow) should be used to keep it. Shared references
+//! can't implement [`Fn`] without overlapping with mutable ones, but they can still be called
+//! through [`Deref`](std::ops::Deref), and passed as `&*reference`.
+//!
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! let mut events = Vec::new();
+//! let (husk, mut callback) = borrow!(Owned::from_box(
+//!     Box::new(|event: u32| events.push(event)) as Box<dyn FnMut(u32)>
+//! ));
+//! callback(1);
+//! // Passed where `impl FnMut` is expected without an adapter closure
+//! [2, 3].into_iter().for_each(callback.reborrow());
+//! drop(callback.reconstruct(husk));
+//! assert_eq!(events, [1, 2, 3]);
+//!
+//! let (husk, double) = borrow!(heap!(|x: u32| x * 2));
+//! let [left, right] = double.split();
+//! assert_eq!(left(2) + right(3), 10);
+//! assert_eq!([1, 2].map(&*left), [2, 4]);
+//! # left.join(right).reconstruct(husk);
+//! ```
+
+use std::marker::Tuple;
+
+use super::RefMut;
+use crate::brand::IsBrand;
+
+impl<Args, T, B> FnOnce<Args> for RefMut<T, B>
+where
+    Args: Tuple,
+    T: ?Sized + FnMut<Args>,
+    B: IsBrand,
+{
+    type Output = T::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(mut self, args: Args) -> Self::Output {
+        self.as_mut().call_mut(args)
+    }
+}
+
+impl<Args, T, B> FnMut<Args> for RefMut<T, B>
+where
+    Args: Tuple,
+    T: ?Sized + FnMut<Args>,
+    B: IsBrand,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: Args) -> Self::Output {
+        self.as_mut().call_mut(args)
+    }
+}
+
+impl<Args, T, B> Fn<Args> for RefMut<T, B>
+where
+    Args: Tuple,
+    T: ?Sized + Fn<Args>,
+    B: IsBrand,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: Args) -> Self::Output {
+        self.as_ref().call(args)
+    }
+}
@@ -0,0 +1,155 @@
+//! Comparison and hashing of referenced values
+//!
+//! These compare the values, not the pointers, so references to equal values are equal
+//! regardless of their brands and levels. Use [`ptr_eq`](crate::ptr_eq) to compare identity.
+//!
+//! References to primitive values and strings can also be compared with plain values on the
+//! right-hand side. Integer and float literals take the type of the referenced value, but values of
+//! other types need to be converted first, same as with plain numbers:
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! let (husk, reference) = borrow!(heap!(5_u64));
+//! assert_eq!(reference, 5);
+//! assert!(reference < 6 && reference > 4);
+//! assert_eq!(reference, u64::from(5_u32));
+//! # reference.reconstruct(husk);
+//! let (husk, reference) = borrow!(heap!(String::from("text")));
+//! assert_eq!(reference, "text");
+//! # reference.reconstruct(husk);
+//! ```
+//! ```compile_fail
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! let (husk, reference) = borrow!(heap!(5_u64));
+//! assert_eq!(reference, 5_u32);
+//! ```
+//!
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! use std::collections::{BTreeSet, HashSet};
+//!
+//! let (husk1, reference1) = borrow!(heap!(String::from("a")));
+//! let (husk2, reference2) = borrow!(heap!(String::from("a")));
+//! let [left, right] = reference1.split();
+//! assert_eq!(left, reference2);
+//! assert!(!ptr_eq(&left, &reference2));
+//! assert!(ptr_eq(&left, &right));
+//!
+//! let (husk, reference) = borrow!(heap!([3, 1, 3]));
+//! let elements = reference.each_mut();
+//! assert!(elements[1] < elements[0]);
+//! let unique = HashSet::from(elements);
+//! assert_eq!(unique.len(), 2);
+//! let sorted = BTreeSet::from_iter(unique);
+//! assert_eq!(**sorted.first().unwrap(), 1);
+//! ```
+
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+use super::Ref;
+use crate::brand::IsBrand;
+
+impl<T, U, B1, B2, const L1: usize, const L2: usize> PartialEq<Ref<U, B2, L2>> for Ref<T, B1, L1>
+where
+    T: ?Sized + PartialEq<U>,
+    U: ?Sized,
+    B1: IsBrand,
+    B2: IsBrand,
+{
+    fn eq(&self, other: &Ref<U, B2, L2>) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl<T, B, const LEVEL: usize> Eq for Ref<T, B, LEVEL>
+where
+    T: ?Sized + Eq,
+    B: IsBrand,
+{
+}
+
+impl<T, U, B1, B2, const L1: usize, const L2: usize> PartialOrd<Ref<U, B2, L2>> for Ref<T, B1, L1>
+where
+    T: ?Sized + PartialOrd<U>,
+    U: ?Sized,
+    B1: IsBrand,
+    B2: IsBrand,
+{
+    fn partial_cmp(&self, other: &Ref<U, B2, L2>) -> Option<Ordering> {
+        self.as_ref().partial_cmp(other.as_ref())
+    }
+}
+
+impl<T, B, const LEVEL: usize> Ord for Ref<T, B, LEVEL>
+where
+    T: ?Sized + Ord,
+    B: IsBrand,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_ref().cmp(other.as_ref())
+    }
+}
+
+impl<T, B, const LEVEL: usize> Hash for Ref<T, B, LEVEL>
+where
+    T: ?Sized + Hash,
+    B: IsBrand,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_ref().hash(state);
+    }
+}
+
+/// Comparisons with plain values. A blanket implementation for all `T` would overlap with the
+/// comparisons of references, so these are only implemented for primitive types and strings.
+/// Comparisons in the other direction, like `PartialEq<Ref<i32, ..>> for i32`, would make
+/// comparisons of plain values ambiguous, e.g. `vec![1] == []`, so they're not implemented.
+macro_rules! impl_value_cmp {
+    ($($ty:ty),*) => {$(
+        impl<B, const LEVEL: usize> PartialEq<$ty> for Ref<$ty, B, LEVEL>
+        where
+            B: IsBrand,
+        {
+            fn eq(&self, other: &$ty) -> bool {
+                self.as_ref() == other
+            }
+        }
+
+        impl<B, const LEVEL: usize> PartialOrd<$ty> for Ref<$ty, B, LEVEL>
+        where
+            B: IsBrand,
+        {
+            fn partial_cmp(&self, other: &$ty) -> Option<Ordering> {
+                self.as_ref().partial_cmp(other)
+            }
+        }
+    )*};
+}
+
+impl_value_cmp!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char, str,
+    String
+);
+
+impl<B, const LEVEL: usize> PartialEq<&str> for Ref<str, B, LEVEL>
+where
+    B: IsBrand,
+{
+    fn eq(&self, other: &&str) -> bool {
+        self.as_ref() == *other
+    }
+}
+
+impl<B, const LEVEL: usize> PartialEq<&str> for Ref<String, B, LEVEL>
+where
+    B: IsBrand,
+{
+    fn eq(&self, other: &&str) -> bool {
+        self.as_ref() == other
+    }
+}
@@ -0,0 +1,120 @@
+//! Standard conversion traits for passing references to generic APIs
+//!
+//! Blanket implementations like `AsRef<U> for Ref<T, ..> where T: AsRef<U>` would overlap with
+//! `AsRef<T>`, so the transitive conversions are only implemented for strings and vectors.
+//!
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! use std::collections::HashMap;
+//!
+//! fn len(bytes: impl AsRef<[u8]>) -> usize {
+//!     bytes.as_ref().len()
+//! }
+//!
+//! let (husk, reference) = borrow!(heap!([String::from("a"), String::from("bc")]));
+//! let [a, bc] = reference.each_mut();
+//! assert_eq!(len(&bc), 2);
+//! // Keys are looked up by `&str` through `Borrow<str>`
+//! let lengths = HashMap::from([(a, 1), (bc, 2)]);
+//! assert_eq!(lengths.get("bc"), Some(&2));
+//! ```
+
+use std::borrow::{Borrow, BorrowMut};
+
+use super::{Ref, RefMut};
+use crate::brand::IsBrand;
+
+impl<T, B, const LEVEL: usize> AsRef<T> for Ref<T, B, LEVEL>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    fn as_ref(&self) -> &T {
+        Ref::as_ref(self)
+    }
+}
+
+impl<T, B> AsMut<T> for RefMut<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    fn as_mut(&mut self) -> &mut T {
+        Ref::as_mut(self)
+    }
+}
+
+impl<T, B, const LEVEL: usize> Borrow<T> for Ref<T, B, LEVEL>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    fn borrow(&self) -> &T {
+        Ref::as_ref(self)
+    }
+}
+
+impl<T, B> BorrowMut<T> for RefMut<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    fn borrow_mut(&mut self) -> &mut T {
+        Ref::as_mut(self)
+    }
+}
+
+impl<B, const LEVEL: usize> AsRef<str> for Ref<String, B, LEVEL>
+where
+    B: IsBrand,
+{
+    fn as_ref(&self) -> &str {
+        Ref::as_ref(self).as_str()
+    }
+}
+
+impl<B, const LEVEL: usize> AsRef<[u8]> for Ref<String, B, LEVEL>
+where
+    B: IsBrand,
+{
+    fn as_ref(&self) -> &[u8] {
+        Ref::as_ref(self).as_bytes()
+    }
+}
+
+impl<B, const LEVEL: usize> Borrow<str> for Ref<String, B, LEVEL>
+where
+    B: IsBrand,
+{
+    fn borrow(&self) -> &str {
+        Ref::as_ref(self).as_str()
+    }
+}
+
+impl<T, B, const LEVEL: usize> AsRef<[T]> for Ref<Vec<T>, B, LEVEL>
+where
+    B: IsBrand,
+{
+    fn as_ref(&self) -> &[T] {
+        Ref::as_ref(self).as_slice()
+    }
+}
+
+impl<T, B> AsMut<[T]> for RefMut<Vec<T>, B>
+where
+    B: IsBrand,
+{
+    fn as_mut(&mut self) -> &mut [T] {
+        Ref::as_mut(self).as_mut_slice()
+    }
+}
+
+impl<T, B, const LEVEL: usize> Borrow<[T]> for Ref<Vec<T>, B, LEVEL>
+where
+    B: IsBrand,
+{
+    fn borrow(&self) -> &[T] {
+        Ref::as_ref(self).as_slice()
+    }
+}
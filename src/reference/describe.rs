@@ -0,0 +1,111 @@
+//! Introspection of reference levels and types for diagnostics
+
+use std::{any, fmt};
+
+use super::Ref;
+use crate::brand::IsBrand;
+
+/// Description of a [`Ref`] for diagnostics, created by [`.describe()`](Ref::describe).
+/// Displayed as the type of the reference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RefDescription {
+    level: usize,
+    type_name: &'static str,
+    brand_name: &'static str,
+}
+
+impl RefDescription {
+    /// Level of the reference
+    #[must_use]
+    pub fn level(&self) -> usize {
+        self.level
+    }
+
+    /// Name of the type of the value, as given by [`any::type_name`]
+    #[must_use]
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Name of the brand type, as given by [`any::type_name`]
+    #[must_use]
+    pub fn brand_name(&self) -> &'static str {
+        self.brand_name
+    }
+}
+
+impl fmt::Display for RefDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Ref<{}, {}, {}>",
+            self.type_name, self.brand_name, self.level
+        )
+    }
+}
+
+impl<T, B, const L: usize> Ref<T, B, L>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    /// Level of references of this type. References of level 0 are mutable.
+    pub const LEVEL: usize = L;
+
+    /// Level of this reference, same as [`Ref::LEVEL`]
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// fn is_shared<T, B: brand::IsBrand, const LEVEL: usize>(_: &Ref<T, B, LEVEL>) -> bool {
+    ///     Ref::<T, B, LEVEL>::LEVEL > 0
+    /// }
+    ///
+    /// let (husk, reference) = borrow!(heap!(5));
+    /// assert!(reference.is_mut());
+    /// let [left, right] = reference.split();
+    /// let [left1, left2] = left.split();
+    /// assert_eq!((left1.level(), right.level()), (2, 1));
+    /// assert!(!right.is_mut());
+    /// assert!(is_shared(&left1));
+    /// let left = left1.join(left2);
+    /// assert_eq!(left.level(), 1);
+    /// let reference = left.join(right);
+    /// assert!(!is_shared(&reference));
+    /// # reference.reconstruct(husk);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn level(&self) -> usize {
+        L
+    }
+
+    /// Whether this reference is mutable, i.e. has level 0
+    #[inline]
+    #[must_use]
+    pub const fn is_mut(&self) -> bool {
+        L == 0
+    }
+
+    /// Describe this reference for diagnostics
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap!(5_u8));
+    /// let [left, right] = reference.split();
+    /// let description = left.describe();
+    /// assert_eq!(description.level(), 1);
+    /// assert_eq!(description.type_name(), "u8");
+    /// assert!(description.to_string().starts_with("Ref<u8, "));
+    /// # left.join(right).reconstruct(husk);
+    /// ```
+    #[must_use]
+    pub fn describe(&self) -> RefDescription {
+        RefDescription {
+            level: L,
+            type_name: any::type_name::<T>(),
+            brand_name: any::type_name::<B>(),
+        }
+    }
+}
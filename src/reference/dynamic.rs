@@ -0,0 +1,268 @@
+//! References with level known only at runtime
+
+use std::{error::Error, fmt, marker::PhantomData, ops::Deref, ptr::NonNull};
+
+use super::{same_place, Invariant, Ref, RefMut};
+use crate::{
+    brand::IsBrand,
+    owned::{Husk, OwnershipKind},
+    Owned,
+};
+
+/// [`Ref`] with its level erased into a runtime value, so references of different levels can be
+/// stored together. Levels are checked at runtime when joining, converting back to a [`Ref`] or
+/// reconstructing.
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// let (husk, reference) = borrow!(heap!(5));
+/// let [left, right] = reference.split();
+/// let [middle, right] = right.split();
+/// let mut handles: Vec<DynRef<_, _>> = vec![left.into(), middle.into(), right.into()];
+/// assert!(handles.iter().all(|handle| **handle == 5));
+///
+/// let right = handles.pop().unwrap();
+/// let middle = handles.pop().unwrap();
+/// let left = handles.pop().unwrap();
+/// assert_eq!((left.level(), middle.level()), (1, 2));
+/// let joined = left.try_join(middle.try_join(right)?)?;
+/// let owned = joined.try_reconstruct(husk).ok().unwrap();
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct DynRef<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    ptr: NonNull<T>,
+    variance: Invariant<T>,
+    brand: B,
+    level: usize,
+}
+
+impl<T, B, const LEVEL: usize> From<Ref<T, B, LEVEL>> for DynRef<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    fn from(reference: Ref<T, B, LEVEL>) -> Self {
+        Self {
+            ptr: reference.ptr,
+            variance: PhantomData,
+            brand: reference.into_brand(),
+            level: LEVEL,
+        }
+    }
+}
+
+impl<T, B> DynRef<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    /// Level of this reference, like [`Ref::level`]
+    #[must_use]
+    pub const fn level(&self) -> usize {
+        self.level
+    }
+
+    /// Split this reference into two references with incremented level, like [`Ref::split`]
+    #[must_use]
+    pub fn split(self) -> [Self; 2] {
+        let level = self.level + 1;
+        // SAFETY: we're using `.duplicate()` to split a reference
+        let (brand1, brand2) = unsafe { self.brand.duplicate() };
+        [
+            Self {
+                ptr: self.ptr,
+                variance: PhantomData,
+                brand: brand1,
+                level,
+            },
+            Self {
+                ptr: self.ptr,
+                variance: PhantomData,
+                brand: brand2,
+                level,
+            },
+        ]
+    }
+
+    /// Join this reference with another one of the same level, decrementing the level, like
+    /// [`Ref::join`]
+    ///
+    /// # Errors
+    /// Fails if the references are mutable, have different levels or point to different places.
+    /// The error contains both references.
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// use nolife::JoinErrorKind;
+    ///
+    /// let (husk, reference) = borrow!(heap!(5));
+    /// let [left, right] = reference.split();
+    /// let [middle, right] = right.split();
+    /// let error = DynRef::from(left).try_join(middle.into()).err().unwrap();
+    /// assert_eq!(error.kind(), JoinErrorKind::DifferentLevels);
+    /// ```
+    pub fn try_join(self, other: Self) -> Result<Self, JoinError<T, B>> {
+        let kind = if self.level != other.level {
+            JoinErrorKind::DifferentLevels
+        } else if self.level == 0 {
+            JoinErrorKind::Mutable
+        } else if !same_place(self.ptr, other.ptr) {
+            JoinErrorKind::DifferentPlaces
+        } else {
+            return Ok(Self {
+                ptr: self.ptr,
+                variance: PhantomData,
+                brand: self.brand,
+                level: self.level - 1,
+            });
+        };
+        Err(JoinError {
+            references: [self, other],
+            kind,
+        })
+    }
+
+    /// Convert this reference back to a [`Ref`] with the static level `LEVEL`
+    ///
+    /// # Errors
+    /// Gives the reference back if its level isn't `LEVEL`.
+    pub fn try_into_ref<const LEVEL: usize>(self) -> Result<Ref<T, B, LEVEL>, Self> {
+        if self.level == LEVEL {
+            // SAFETY: the reference was created from a `Ref` of this level, or split and joined
+            // with level tracked the same way as `Ref`s do
+            Ok(unsafe { Ref::new(self.ptr, self.brand) })
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Convert this reference back to a [`RefMut`]
+    ///
+    /// # Errors
+    /// Gives the reference back if it's not mutable, i.e. its level isn't 0.
+    pub fn try_into_mut(self) -> Result<RefMut<T, B>, Self> {
+        self.try_into_ref()
+    }
+
+    /// Join this reference with [`Husk`], reconstructing the owned value, like
+    /// [`Ref::reconstruct`]
+    ///
+    /// # Errors
+    /// Gives the reference and the husk back if the reference is not mutable, i.e. its level
+    /// isn't 0.
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap!(5));
+    /// let [left, right] = DynRef::from(reference).split();
+    /// let (left, husk) = left.try_reconstruct(husk).err().unwrap();
+    /// let owned = left.try_join(right)?.try_reconstruct(husk).ok().unwrap();
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn try_reconstruct<U, Kind>(
+        self,
+        husk: Husk<U, B, Kind>,
+    ) -> Result<Owned<U, Kind>, (Self, Husk<U, B, Kind>)>
+    where
+        U: ?Sized,
+        Kind: OwnershipKind<U, Pointee = T>,
+    {
+        match self.try_into_mut() {
+            Ok(reference) => Ok(reference.reconstruct(husk)),
+            Err(reference) => Err((reference, husk)),
+        }
+    }
+}
+
+impl<T, B> Deref for DynRef<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: every `Ref` allows shared access
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+/// The reason why [`DynRef::try_join`] failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinErrorKind {
+    /// The references have different levels
+    DifferentLevels,
+    /// The references are mutable, so they need to be reconstructed instead
+    Mutable,
+    /// The references point to different places
+    DifferentPlaces,
+}
+
+/// References given to [`DynRef::try_join`] can't be joined. Contains the original references.
+pub struct JoinError<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    references: [DynRef<T, B>; 2],
+    kind: JoinErrorKind,
+}
+
+impl<T, B> JoinError<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    /// Obtain the original references back
+    #[must_use]
+    pub fn into_references(self) -> [DynRef<T, B>; 2] {
+        self.references
+    }
+
+    /// Why the references can't be joined
+    #[must_use]
+    pub fn kind(&self) -> JoinErrorKind {
+        self.kind
+    }
+}
+
+impl<T, B> fmt::Debug for JoinError<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinError")
+            .field("kind", &self.kind)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, B> fmt::Display for JoinError<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.kind {
+            JoinErrorKind::DifferentLevels => "joined references have different levels",
+            JoinErrorKind::Mutable => "cannot join mutable (level-0) references",
+            JoinErrorKind::DifferentPlaces => "joined references point to different places",
+        })
+    }
+}
+
+impl<T, B> Error for JoinError<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+}
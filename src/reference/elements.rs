@@ -0,0 +1,78 @@
+//! Splitting references to arrays into references to their elements
+
+use std::array;
+
+use super::{NonEmpty, Ref, RefMut, Reunite};
+use crate::brand::{Element, IsBrand};
+
+impl<T, B, const N: usize> RefMut<[T; N], B>
+where
+    B: IsBrand,
+{
+    /// Split this reference into mutable references to every element, like [`array::each_mut`].
+    /// The elements can be joined back with [`.reunite()`](Reunite::reunite), which checks that
+    /// all of them are in place.
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap!([1, 2, 3]));
+    /// let [mut first, second, mut third] = reference.each_mut();
+    /// // All elements are mutable at the same time
+    /// *first += *second;
+    /// *third *= 2;
+    /// let owned = [first, second, third].reunite().reconstruct(husk);
+    /// assert_eq!(owned.into_inner(), [3, 2, 6]);
+    /// ```
+    ///
+    /// The owned value can't be reconstructed while elements are borrowed:
+    /// ```compile_fail
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap!([1, 2]));
+    /// let [first, second] = reference.each_mut();
+    /// let owned = first.reconstruct(husk);
+    /// ```
+    ///
+    /// Elements must be reunited in their original order:
+    /// ```should_panic
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap!([1, 2]));
+    /// let [first, second] = reference.each_mut();
+    /// let reference = [second, first].reunite();
+    /// ```
+    #[must_use]
+    pub fn each_mut(self) -> [RefMut<T, Element<B>>; N] {
+        let data = self.ptr.cast::<T>();
+        // SAFETY: elements are disjoint, and the reference to the whole array can only be obtained
+        // back by `.reunite()`, which checks that all of them are in place
+        array::from_fn(|index| unsafe { Ref::new(data.add(index), Element::new()) })
+    }
+}
+
+impl<T, B, const N: usize> Reunite for [RefMut<T, Element<B>>; N]
+where
+    B: IsBrand,
+{
+    type Whole = RefMut<[T; N], B>;
+
+    /// # Panics
+    /// Panics if the elements are not in their original order or belong to different arrays.
+    fn reunite(self) -> Self::Whole {
+        let () = NonEmpty::<N>::ASSERT;
+        let Some(first) = self.first() else {
+            unreachable!()
+        };
+        let data = first.ptr;
+        assert!(
+            self.iter()
+                .enumerate()
+                .all(|(index, element)| element.is_at(data.as_ptr().wrapping_add(index))),
+            "element references are not in place"
+        );
+        // SAFETY: all elements are consumed and were in place, so the array is exclusively borrowed
+        // again by the reference with the original brand
+        unsafe { Ref::new(data.cast(), B::conjure()) }
+    }
+}
@@ -0,0 +1,222 @@
+//! References with brands checked at runtime
+
+use std::{
+    error::Error,
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use super::{parent_level, Invariant, RefMut};
+use crate::{
+    brand::IsBrand,
+    owned::{Husk, OwnershipKind},
+    Owned,
+};
+
+/// Source of unique ids for erased brands
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// [`Ref`](crate::Ref) with its brand replaced by a runtime id, so references obtained from
+/// different [`borrow!`](crate::borrow!) calls can be stored together. Created by
+/// [`RefMut::erase`], which assigns a new id to the reference and its [`ErasedHusk`].
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// let (husk1, reference1) = borrow!(heap!(1));
+/// let (husk2, reference2) = borrow!(heap!(2));
+/// let (husk1, reference1) = reference1.erase(husk1);
+/// let (husk2, reference2) = reference2.erase(husk2);
+/// let mut references = vec![reference1, reference2];
+/// for reference in &mut references {
+///     **reference *= 10;
+/// }
+///
+/// let reference2 = references.pop().unwrap();
+/// let reference1 = references.pop().unwrap();
+/// // Wrong pairs are detected at runtime
+/// let (reference1, husk2) = reference1.try_reconstruct(husk2).err().unwrap().into_inner();
+/// assert_eq!(reference1.try_reconstruct(husk1)?.into_inner(), 10);
+/// assert_eq!(reference2.try_reconstruct(husk2)?.into_inner(), 20);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct ErasedRef<T, const LEVEL: usize>
+where
+    T: ?Sized,
+{
+    ptr: NonNull<T>,
+    variance: Invariant<T>,
+    id: u64,
+}
+
+/// Mutable [`ErasedRef`]
+pub type ErasedRefMut<T> = ErasedRef<T, 0>;
+
+/// [`Husk`] with its brand replaced by a runtime id, see [`ErasedRef`]
+pub struct ErasedHusk<T, Kind>
+where
+    T: ?Sized,
+    Kind: OwnershipKind<T>,
+{
+    inner: Kind::Husk,
+    id: u64,
+}
+
+impl<T, B> RefMut<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    /// Replace the brand of this reference and its husk by a new runtime id, see [`ErasedRef`]
+    pub fn erase<U, Kind>(self, husk: Husk<U, B, Kind>) -> (ErasedHusk<U, Kind>, ErasedRefMut<T>)
+    where
+        U: ?Sized,
+        Kind: OwnershipKind<U, Pointee = T>,
+    {
+        // Ids are only compared for equality, so the order of increments doesn't matter
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        (
+            ErasedHusk {
+                inner: husk.into_inner(),
+                id,
+            },
+            ErasedRef {
+                ptr: self.ptr,
+                variance: PhantomData,
+                id,
+            },
+        )
+    }
+}
+
+impl<T, const LEVEL: usize> ErasedRef<T, LEVEL>
+where
+    T: ?Sized,
+{
+    /// Split this reference into two immutable references with incremented level, like
+    /// [`Ref::split`](crate::Ref::split)
+    #[must_use]
+    pub fn split(self) -> [ErasedRef<T, { LEVEL + 1 }>; 2] {
+        [
+            ErasedRef {
+                ptr: self.ptr,
+                variance: PhantomData,
+                id: self.id,
+            },
+            ErasedRef {
+                ptr: self.ptr,
+                variance: PhantomData,
+                id: self.id,
+            },
+        ]
+    }
+
+    /// Join this reference with another one of the same level, decrementing the level, like
+    /// [`Ref::join`](crate::Ref::join)
+    ///
+    /// # Errors
+    /// Gives both references back if they were erased separately.
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk1, reference1) = borrow!(heap!(1));
+    /// let (husk2, reference2) = borrow!(heap!(1));
+    /// let [left1, right1] = reference1.erase(husk1).1.split();
+    /// let [left2, right2] = reference2.erase(husk2).1.split();
+    /// assert!(left1.try_join(right2).is_err());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn try_join(
+        self,
+        other: Self,
+    ) -> Result<ErasedRef<T, { parent_level(LEVEL) }>, BrandMismatch<(Self, Self)>> {
+        if self.id == other.id {
+            Ok(ErasedRef {
+                ptr: self.ptr,
+                variance: PhantomData,
+                id: self.id,
+            })
+        } else {
+            Err(BrandMismatch((self, other)))
+        }
+    }
+}
+
+impl<T> ErasedRefMut<T>
+where
+    T: ?Sized,
+{
+    /// Join this reference with [`ErasedHusk`], reconstructing the owned value, like
+    /// [`Ref::reconstruct`](crate::Ref::reconstruct)
+    ///
+    /// # Errors
+    /// Gives the reference and the husk back if they were erased separately.
+    #[allow(clippy::type_complexity)]
+    pub fn try_reconstruct<U, Kind>(
+        self,
+        husk: ErasedHusk<U, Kind>,
+    ) -> Result<Owned<U, Kind>, BrandMismatch<(Self, ErasedHusk<U, Kind>)>>
+    where
+        U: ?Sized,
+        Kind: OwnershipKind<U, Pointee = T>,
+    {
+        if self.id == husk.id {
+            // SAFETY: the reference and the husk were obtained together from `.erase()`, and this
+            // is the only mutable reference with their id
+            Ok(unsafe { Owned::from_inner(Kind::join(husk.inner, self.ptr)) })
+        } else {
+            Err(BrandMismatch((self, husk)))
+        }
+    }
+}
+
+impl<T, const LEVEL: usize> Deref for ErasedRef<T, LEVEL>
+where
+    T: ?Sized,
+{
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: every reference allows shared access
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<T> DerefMut for ErasedRefMut<T>
+where
+    T: ?Sized,
+{
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: this is the only reference with its id, and it's mutable
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+/// Erased references or husks have different ids. Contains the original values.
+pub struct BrandMismatch<T>(T);
+
+impl<T> BrandMismatch<T> {
+    /// Obtain the original values back
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> fmt::Debug for BrandMismatch<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BrandMismatch").finish_non_exhaustive()
+    }
+}
+
+impl<T> fmt::Display for BrandMismatch<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("erased brands are different")
+    }
+}
+
+impl<T> Error for BrandMismatch<T> {}
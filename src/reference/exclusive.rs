@@ -0,0 +1,83 @@
+//! Sharing a mutable reference while keeping the right to mutate it again
+
+use std::{marker::PhantomData, ptr::NonNull};
+
+use super::{same_place, Invariant, Ref, RefMut};
+use crate::brand::IsBrand;
+
+/// The right to make a shared reference mutable again, created by [`.share()`](Ref::share).
+/// Works like the other half of a [`.split()`](Ref::split), but doesn't give access to the value.
+pub struct Exclusive<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    ptr: NonNull<T>,
+    variance: Invariant<T>,
+    brand: B,
+}
+
+impl<T, B> RefMut<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    /// Turn this reference into a shared one, keeping an [`Exclusive`] token to obtain the mutable
+    /// reference back with [`RefMut::reclaim`]
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// struct Reader<T, B: brand::IsBrand> {
+    ///     data: Ref<Vec<T>, B, 1>,
+    /// }
+    ///
+    /// let (husk, reference) = borrow!(heap!(vec![1, 2, 3]));
+    /// let (shared, exclusive) = reference.share();
+    /// let reader = Reader { data: shared };
+    /// assert_eq!(reader.data.len(), 3);
+    /// let mut reference = RefMut::reclaim(reader.data, exclusive);
+    /// reference.push(4);
+    /// let owned = reference.reconstruct(husk);
+    /// ```
+    ///
+    /// Tokens only reclaim references with the same brand:
+    /// ```compile_fail
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk1, reference1) = borrow!(heap!(1));
+    /// let (husk2, reference2) = borrow!(heap!(2));
+    /// let (shared1, _) = reference1.share();
+    /// let (_, exclusive2) = reference2.share();
+    /// let reference = RefMut::reclaim(shared1, exclusive2);
+    /// ```
+    #[must_use]
+    pub fn share(self) -> (Ref<T, B, 1>, Exclusive<T, B>) {
+        // SAFETY: we're using `.duplicate()` to split a reference
+        let ptr = self.ptr;
+        let (brand1, brand2) = unsafe { self.into_brand().duplicate() };
+        let exclusive = Exclusive {
+            ptr,
+            variance: PhantomData,
+            brand: brand2,
+        };
+        // SAFETY: the token stands for the other reference of level 1
+        (unsafe { Ref::new(ptr, brand1) }, exclusive)
+    }
+
+    /// Obtain the mutable reference back from a shared one and its [`Exclusive`] token
+    ///
+    /// # Panics
+    /// Panics if the token was created for another place, which is only possible for references
+    /// to different elements branded with [`Element`](crate::brand::Element).
+    // The reference must be consumed, so it can't be used after reclaiming
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn reclaim(shared: Ref<T, B, 1>, exclusive: Exclusive<T, B>) -> Self {
+        assert!(
+            same_place(shared.ptr, exclusive.ptr),
+            "reclaimed reference is not the shared one"
+        );
+        // SAFETY: both halves are consumed and point to the same place, same as in `.join()`
+        unsafe { Ref::new(shared.ptr, exclusive.brand) }
+    }
+}
@@ -0,0 +1,181 @@
+//! Splitting references to structs and tuples into references to their fields
+
+use std::{
+    mem::offset_of,
+    ptr::{addr_of_mut, NonNull},
+};
+
+use super::{Ref, RefMut};
+use crate::brand::{Field, IsBrand};
+
+/// Types whose mutable references can be split into disjoint mutable references to their fields.
+/// Implemented by `#[derive(Split)]`, which is available with the `derive` feature.
+///
+/// The derive generates a `<Name>Fields<B>` struct with a [`RefMut`] for every field, each branded
+/// with its own [`Field`](crate::brand::Field) brand, and a `.reunite()` method which gives back
+/// the reference to the whole value once all of the fields are back in place.
+#[cfg_attr(
+    feature = "derive",
+    doc = r#"
+```
+# #![feature(generic_const_exprs)]
+# use nolife::*;
+#[derive(Split)]
+struct World {
+    physics: Vec<f32>,
+    render: String,
+}
+
+let world = heap!(World { physics: vec![0.0], render: String::new() });
+let (husk, reference) = borrow!(world);
+let mut fields = reference.split_fields();
+// Both fields are mutably borrowed at the same time
+fields.physics.push(1.0);
+fields.render.push_str("frame");
+let world = fields.reunite().reconstruct(husk).into_inner();
+assert_eq!(world.physics, [0.0, 1.0]);
+assert_eq!(world.render, "frame");
+```
+
+Field references can't be reunited while some of them are still borrowed elsewhere:
+```compile_fail
+# #![feature(generic_const_exprs)]
+# use nolife::*;
+#[derive(Split)]
+struct World {
+    physics: Vec<f32>,
+    render: String,
+}
+
+let (husk, reference) = borrow!(heap!(World { physics: vec![], render: String::new() }));
+let fields = reference.split_fields();
+let physics = fields.physics;
+let reference = fields.reunite();
+```
+"#
+)]
+///
+/// # Safety
+/// `Fields<B>` must only contain references to disjoint parts of the value, none of which is
+/// branded with `B` itself, and must only allow obtaining a `RefMut<Self, B>` back after all of
+/// them are consumed.
+pub unsafe trait Split {
+    /// References to the fields, generated by `#[derive(Split)]`
+    type Fields<B: IsBrand>;
+
+    /// An implementation detail of [`RefMut::split_fields`]
+    ///
+    /// # Safety
+    /// `ptr` must be exclusively borrowed through a [`RefMut`] branded with `brand`, which is
+    /// consumed by this call.
+    #[doc(hidden)]
+    unsafe fn split_fields<B: IsBrand>(ptr: NonNull<Self>, brand: B) -> Self::Fields<B>;
+}
+
+impl<T, B> RefMut<T, B>
+where
+    T: Split,
+    B: IsBrand,
+{
+    /// Split this reference into disjoint mutable references to the fields of the value
+    #[must_use]
+    pub fn split_fields(self) -> T::Fields<B> {
+        // SAFETY: this reference is consumed, so the value is exclusively borrowed by the fields
+        unsafe { T::split_fields(self.ptr, self.into_brand()) }
+    }
+}
+
+/// Field references which can be joined back into a reference to the whole value, like the ones
+/// returned by [`.split_tuple()`](Ref::split_tuple). Implemented for tuples of 2, 3 and 4
+/// references.
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// let (husk, reference) = borrow!(heap!((vec![1], String::from("metrics"))));
+/// let (mut state, mut metrics) = reference.split_tuple();
+/// // Both halves are mutable at the same time
+/// state.push(2);
+/// metrics.push_str(": 2");
+/// let (state, metrics) = (state, metrics).reunite().reconstruct(husk).into_inner();
+/// assert_eq!(state, [1, 2]);
+/// assert_eq!(metrics, "metrics: 2");
+/// ```
+///
+/// Halves of different tuples can't be reunited:
+/// ```compile_fail
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// let (husk1, reference1) = borrow!(heap!((1, 2)));
+/// let (husk2, reference2) = borrow!(heap!((3, 4)));
+/// let (first1, _) = reference1.split_tuple();
+/// let (_, second2) = reference2.split_tuple();
+/// let reference = (first1, second2).reunite();
+/// ```
+pub trait Reunite {
+    /// Reference to the whole value
+    type Whole;
+
+    /// Join the field references back into a reference to the whole value
+    fn reunite(self) -> Self::Whole;
+}
+
+macro_rules! tuple_impls {
+    ($($ty:ident $index:tt),+) => {
+        // SAFETY: tuple fields are disjoint and get distinct brands, and the reference to the whole
+        // tuple can only be obtained back by `Reunite`, which consumes all of them
+        unsafe impl<$($ty),+> Split for ($($ty,)+) {
+            type Fields<B: IsBrand> = ($(RefMut<$ty, Field<B, $index>>,)+);
+
+            unsafe fn split_fields<B: IsBrand>(ptr: NonNull<Self>, _brand: B) -> Self::Fields<B> {
+                let raw = ptr.as_ptr();
+                // SAFETY: `ptr` is exclusively borrowed by the caller, and each field gets its own
+                // brand
+                unsafe {
+                    ($(
+                        Ref::new(NonNull::new_unchecked(addr_of_mut!((*raw).$index)), Field::new()),
+                    )+)
+                }
+            }
+        }
+
+        impl<$($ty,)+ B> Reunite for ($(RefMut<$ty, Field<B, $index>>,)+)
+        where
+            B: IsBrand,
+        {
+            type Whole = RefMut<($($ty,)+), B>;
+
+            fn reunite(self) -> Self::Whole {
+                // SAFETY: the field pointer points into the tuple it was split from
+                let ptr: NonNull<($($ty,)+)> =
+                    unsafe { self.0.ptr.byte_sub(offset_of!(($($ty,)+), 0)).cast() };
+                let raw = ptr.as_ptr();
+                // Fields with `Element` brands could come from different tuples
+                // SAFETY: `ptr` points to a valid tuple, found by its first field
+                assert!(
+                    $(self.$index.is_at(unsafe { addr_of_mut!((*raw).$index) }))&&+,
+                    "field references belong to different values"
+                );
+                // SAFETY: all field references are consumed, so the tuple is exclusively borrowed
+                // again by the reference with the original brand
+                unsafe { Ref::new(ptr, B::conjure()) }
+            }
+        }
+
+        impl<$($ty,)+ B> RefMut<($($ty,)+), B>
+        where
+            B: IsBrand,
+        {
+            /// Split this reference into mutable references to the tuple fields, which can be
+            /// joined back with [`.reunite()`](Reunite::reunite)
+            #[must_use]
+            pub fn split_tuple(self) -> ($(RefMut<$ty, Field<B, $index>>,)+) {
+                self.split_fields()
+            }
+        }
+    };
+}
+
+tuple_impls!(T0 0, T1 1);
+tuple_impls!(T0 0, T1 1, T2 2);
+tuple_impls!(T0 0, T1 1, T2 2, T3 3);
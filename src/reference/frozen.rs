@@ -0,0 +1,177 @@
+//! Shared handles which are freely copyable, but can't be joined back
+
+use std::{marker::PhantomData, ops::Deref, ptr::NonNull};
+
+use super::{Invariant, Ref, RefMut};
+use crate::{
+    brand::{IsBrand, Scope},
+    owned::{Husk, OwnershipKind},
+    Owned,
+};
+
+/// Copyable shared handle to a value, created by [`.freeze()`](Ref::freeze). Unlike shared
+/// [`Ref`]s, it doesn't need to be joined, so it can be copied into any number of closures, but
+/// there's no safe way to mutate or reconstruct the value again.
+///
+/// If the husk is dropped, the value is leaked, so the handles stay valid forever.
+pub struct Frozen<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    ptr: NonNull<T>,
+    variance: Invariant<T>,
+    brand: PhantomData<B>,
+}
+
+impl<T, B> Clone for Frozen<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, B> Copy for Frozen<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+}
+
+impl<T, B> Deref for Frozen<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: the value is never mutated or released while handles may be used, since that
+        // requires `.thaw()` or `.reclaim_frozen()`
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<T, B> Frozen<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    /// Turn the handle back into a mutable reference
+    ///
+    /// # Safety
+    /// No copies of this handle, nor plain references obtained from them, may be used afterwards
+    #[must_use]
+    pub unsafe fn thaw(self) -> RefMut<T, B> {
+        // SAFETY: the handle was created from a `RefMut` with this brand, and the caller
+        // guarantees that it's the only one left
+        unsafe { Ref::new(self.ptr, B::conjure()) }
+    }
+}
+
+impl<T, B> RefMut<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    /// Give up mutability and reconstructibility for a [`Copy`] shared handle
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap!(vec![1, 2, 3]));
+    /// let frozen = reference.freeze();
+    /// let sums: Vec<_> = (0..3).map(|skip| move || frozen.iter().skip(skip).sum::<i32>()).collect();
+    /// assert_eq!(sums.iter().map(|sum| sum()).collect::<Vec<_>>(), [6, 5, 3]);
+    /// // SAFETY: all closures holding copies of `frozen` are dropped
+    /// drop(sums);
+    /// let owned = unsafe { husk.reclaim_frozen(frozen) };
+    /// ```
+    ///
+    /// Frozen handles don't give mutable access:
+    /// ```compile_fail
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap!(vec![1, 2, 3]));
+    /// let mut frozen = reference.freeze();
+    /// frozen.push(4);
+    /// ```
+    #[must_use]
+    pub fn freeze(self) -> Frozen<T, B> {
+        Frozen {
+            ptr: self.ptr,
+            variance: PhantomData,
+            brand: PhantomData,
+        }
+    }
+
+    /// Temporarily give up mutability for a [`Copy`] shared handle, which can only be used inside
+    /// of `f`, and obtain the mutable reference back afterwards
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap!(vec![1, 2, 3]));
+    /// let (mut reference, sums) = reference.with_frozen(|frozen| {
+    ///     (0..3)
+    ///         .map(|skip| move || frozen.iter().skip(skip).sum::<i32>())
+    ///         .map(|sum| sum())
+    ///         .collect::<Vec<_>>()
+    /// });
+    /// assert_eq!(sums, [6, 5, 3]);
+    /// reference.push(4);
+    /// let owned = reference.reconstruct(husk);
+    /// ```
+    ///
+    /// Handles can't escape the closure:
+    /// ```compile_fail
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap!(vec![1, 2, 3]));
+    /// let (mut reference, frozen) = reference.with_frozen(|frozen| frozen);
+    /// reference.clear();
+    /// assert_eq!(frozen.len(), 3);
+    /// ```
+    /// ```compile_fail
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap!(vec![1, 2, 3]));
+    /// let mut escaped = None;
+    /// let (mut reference, ()) = reference.with_frozen(|frozen| escaped = Some(frozen));
+    /// reference.clear();
+    /// assert_eq!(escaped.unwrap().len(), 3);
+    /// ```
+    pub fn with_frozen<R>(
+        self,
+        f: impl for<'scope> FnOnce(Frozen<T, Scope<'scope, B>>) -> R,
+    ) -> (RefMut<T, B>, R) {
+        let frozen = Frozen {
+            ptr: self.ptr,
+            variance: PhantomData,
+            brand: PhantomData,
+        };
+        let result = f(frozen);
+        // SAFETY: handles can't outlive the closure, since `R` and the captured state can't
+        // mention the `'scope` lifetime, so this is the only reference again
+        (unsafe { Ref::new(self.ptr, self.into_brand()) }, result)
+    }
+}
+
+impl<T, B, Kind> Husk<T, B, Kind>
+where
+    T: ?Sized,
+    B: IsBrand,
+    Kind: OwnershipKind<T>,
+{
+    /// Reconstruct the owned value from a [`Frozen`] handle, e.g. to free it
+    ///
+    /// # Safety
+    /// Same as for [`Frozen::thaw`]
+    pub unsafe fn reclaim_frozen(self, frozen: Frozen<Kind::Pointee, B>) -> Owned<T, Kind> {
+        // SAFETY: guaranteed by the caller
+        unsafe { frozen.thaw() }.reconstruct(self)
+    }
+}
@@ -0,0 +1,106 @@
+//! Polling futures in place through mutable references
+//!
+//! Futures which aren't [`Unpin`], like `async` blocks, can be polled when they're created with
+//! [`heap_pin!`](crate::heap_pin!), which guarantees that they're never moved.
+//!
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! use std::{
+//!     future::Future,
+//!     pin::Pin,
+//!     sync::{
+//!         atomic::{AtomicUsize, Ordering},
+//!         Arc,
+//!     },
+//!     task::{Context, Poll, Wake, Waker},
+//! };
+//!
+//! // Counts how many times it was woken
+//! struct CountingWaker(AtomicUsize);
+//!
+//! impl Wake for CountingWaker {
+//!     fn wake(self: Arc<Self>) {
+//!         self.0.fetch_add(1, Ordering::Relaxed);
+//!     }
+//! }
+//!
+//! // Yields to the executor once before completing
+//! let mut yielded = false;
+//! let yield_now = std::future::poll_fn(move |cx| {
+//!     if yielded {
+//!         Poll::Ready(())
+//!     } else {
+//!         yielded = true;
+//!         cx.waker().wake_by_ref();
+//!         Poll::Pending
+//!     }
+//! });
+//!
+//! let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+//! let waker = Waker::from(Arc::clone(&counter));
+//! let mut context = Context::from_waker(&waker);
+//! let (husk, mut task) = borrow!(heap_pin!(async {
+//!     yield_now.await;
+//!     5
+//! }));
+//! assert_eq!(Pin::new(&mut task).poll(&mut context), Poll::Pending);
+//! assert_eq!(counter.0.load(Ordering::Relaxed), 1);
+//! assert_eq!(Pin::new(&mut task).poll(&mut context), Poll::Ready(5));
+//! let owned = task.reconstruct(husk);
+//! ```
+
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use super::RefMut;
+use crate::{brand::IsBrand, Pinned};
+
+/// Polls an [`Unpin`] future
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// use std::{
+///     future::{self, Future},
+///     pin::pin,
+///     task::{Context, Poll, Waker},
+/// };
+///
+/// let (husk, mut reference) = borrow!(heap!(future::ready(5)));
+/// let mut context = Context::from_waker(Waker::noop());
+/// let mut doubled = pin!(async { reference.reborrow().await * 2 });
+/// assert_eq!(doubled.as_mut().poll(&mut context), Poll::Ready(10));
+/// let owned = reference.reconstruct(husk);
+/// ```
+impl<T, B> Future for RefMut<T, B>
+where
+    T: ?Sized + Future + Unpin,
+    B: IsBrand,
+{
+    type Output = T::Output;
+
+    #[inline]
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: the reference is never moved out of, and pinning it doesn't pin the pointee
+        unsafe { self.get_unchecked_mut() }.as_pin_mut().poll(cx)
+    }
+}
+
+/// Polls a future created with [`heap_pin!`](crate::heap_pin!)
+impl<T, B> Future for RefMut<Pinned<T>, B>
+where
+    T: Future,
+    B: IsBrand,
+{
+    type Output = T::Output;
+
+    #[inline]
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: the reference is never moved out of, and pinning it doesn't pin the pointee
+        unsafe { self.get_unchecked_mut() }.pinned_mut().poll(cx)
+    }
+}
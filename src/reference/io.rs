@@ -0,0 +1,238 @@
+//! Using references with [`std::io`]
+
+use std::{
+    fmt,
+    io::{self, Cursor, IoSlice, IoSliceMut, Read, Seek, SeekFrom, Write},
+};
+
+use super::{Ref, RefMut};
+use crate::brand::IsBrand;
+
+impl<B, const LEVEL: usize> Ref<[u8], B, LEVEL>
+where
+    B: IsBrand,
+{
+    /// Read from the buffer with [`Read`] and [`Seek`], starting
+    /// at its beginning
+    #[inline]
+    #[must_use]
+    pub fn reader(&self) -> Cursor<&[u8]> {
+        Cursor::new(self)
+    }
+}
+
+impl<B> RefMut<[u8], B>
+where
+    B: IsBrand,
+{
+    /// Write to the buffer with [`Write`] and [`Seek`], starting
+    /// at its beginning. The cursor only borrows the reference, so it can be used again after.
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// use std::io::{Read, Write};
+    ///
+    /// let (husk, mut reference) = borrow!(heap_slice![0u8; 16]);
+    /// let record = b"hello";
+    /// let mut cursor = reference.cursor();
+    /// cursor.write_all(&u32::try_from(record.len()).unwrap().to_le_bytes())?;
+    /// cursor.write_all(record)?;
+    ///
+    /// let mut reader = reference.reader();
+    /// let mut len = [0; 4];
+    /// reader.read_exact(&mut len)?;
+    /// let mut read = vec![0; u32::from_le_bytes(len) as usize];
+    /// reader.read_exact(&mut read)?;
+    /// assert_eq!(read, record);
+    /// let owned = reference.reconstruct(husk);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn cursor(&mut self) -> Cursor<&mut [u8]> {
+        Cursor::new(self)
+    }
+}
+
+/// Reads from the value, so mutable references can be given to functions taking `impl Read`
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// use std::{
+///     fs::File,
+///     io::{Read, Seek, SeekFrom, Write},
+/// };
+///
+/// fn read_all(mut reader: impl Read) -> std::io::Result<String> {
+///     let mut text = String::new();
+///     reader.read_to_string(&mut text)?;
+///     Ok(text)
+/// }
+///
+/// let path = std::env::temp_dir().join(format!("nolife-io-{}", std::process::id()));
+/// let (husk, mut file) = borrow!(heap!(File::options().create(true).truncate(true).read(true).write(true).open(&path)?));
+/// writeln!(file, "hello")?;
+/// file.seek(SeekFrom::Start(0))?;
+/// assert_eq!(read_all(file.reborrow())?, "hello\n");
+/// drop(file.reconstruct(husk));
+/// std::fs::remove_file(path)?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+impl<T, B> Read for RefMut<T, B>
+where
+    T: ?Sized + Read,
+    B: IsBrand,
+{
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.as_mut().read(buf)
+    }
+
+    #[inline]
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        self.as_mut().read_vectored(bufs)
+    }
+
+    #[inline]
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        self.as_mut().read_to_end(buf)
+    }
+
+    #[inline]
+    fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
+        self.as_mut().read_to_string(buf)
+    }
+
+    #[inline]
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        self.as_mut().read_exact(buf)
+    }
+}
+
+/// Reads through a shared reference to a value which can be read through `&T`, like
+/// [`File`](std::fs::File). For slices this starts at the beginning on every call, so
+/// [`.reader()`](Ref::reader) should be used for them instead.
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// use std::{fs::File, io::Read};
+///
+/// let path = std::env::temp_dir().join(format!("nolife-shared-io-{}", std::process::id()));
+/// std::fs::write(&path, "abcd")?;
+/// let (husk, file) = borrow!(heap!(File::open(&path)?));
+/// let [left, right] = file.split();
+/// let mut buf = [0; 2];
+/// (&left).read_exact(&mut buf)?;
+/// assert_eq!(&buf, b"ab");
+/// // Both references share the position in the file
+/// (&right).read_exact(&mut buf)?;
+/// assert_eq!(&buf, b"cd");
+/// drop(left.join(right).reconstruct(husk));
+/// std::fs::remove_file(path)?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+impl<T, B, const LEVEL: usize> Read for &Ref<T, B, LEVEL>
+where
+    T: ?Sized,
+    for<'a> &'a T: Read,
+    B: IsBrand,
+{
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.as_ref().read(buf)
+    }
+
+    #[inline]
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        self.as_ref().read_vectored(bufs)
+    }
+
+    #[inline]
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        self.as_ref().read_to_end(buf)
+    }
+
+    #[inline]
+    fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
+        self.as_ref().read_to_string(buf)
+    }
+
+    #[inline]
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        self.as_ref().read_exact(buf)
+    }
+}
+
+/// Writes to the value, so mutable references can be given to functions taking `impl Write`
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// use std::io::Write;
+///
+/// fn greet(mut writer: impl Write) -> std::io::Result<()> {
+///     write!(writer, "hello, {}", "world")?;
+///     writer.write_all(b"!")
+/// }
+///
+/// let (husk, mut buffer) = borrow!(heap!(Vec::new()));
+/// greet(buffer.reborrow())?;
+/// greet(&mut buffer)?;
+/// assert_eq!(buffer.reconstruct(husk).into_inner(), b"hello, world!hello, world!");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+impl<T, B> Write for RefMut<T, B>
+where
+    T: ?Sized + Write,
+    B: IsBrand,
+{
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.as_mut().write(buf)
+    }
+
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        self.as_mut().write_vectored(bufs)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        self.as_mut().flush()
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.as_mut().write_all(buf)
+    }
+
+    #[inline]
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> io::Result<()> {
+        self.as_mut().write_fmt(args)
+    }
+}
+
+/// Seeks in the value
+impl<T, B> Seek for RefMut<T, B>
+where
+    T: ?Sized + Seek,
+    B: IsBrand,
+{
+    #[inline]
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.as_mut().seek(pos)
+    }
+
+    #[inline]
+    fn rewind(&mut self) -> io::Result<()> {
+        self.as_mut().rewind()
+    }
+
+    #[inline]
+    fn stream_position(&mut self) -> io::Result<u64> {
+        self.as_mut().stream_position()
+    }
+}
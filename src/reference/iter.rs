@@ -0,0 +1,97 @@
+//! Driving iterators behind mutable references
+//!
+//! A mutable reference to an iterator is an iterator itself, like `&mut I` is. Iterating it by
+//! value consumes the reference, so [`.reborrow()`](super::Ref::reborrow) or `&mut` should be used
+//! to keep it for reconstructing the owned value.
+//!
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! let (husk, mut iter) = borrow!(heap!(vec![1, 2, 3, 4, 5].into_iter()));
+//! let first: Vec<_> = iter.reborrow().take(3).collect();
+//! assert_eq!(first, [1, 2, 3]);
+//! assert_eq!(iter.len(), 2);
+//! assert_eq!(iter.next_back(), Some(5));
+//! for item in &mut iter {
+//!     assert_eq!(item, 4);
+//! }
+//! assert_eq!(iter.reconstruct(husk).into_inner().next(), None);
+//! ```
+
+use std::iter::FusedIterator;
+
+use super::RefMut;
+use crate::brand::IsBrand;
+
+impl<T, B> Iterator for RefMut<T, B>
+where
+    T: ?Sized + Iterator,
+    B: IsBrand,
+{
+    type Item = T::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.as_mut().next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.as_ref().size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.as_mut().nth(n)
+    }
+
+    #[inline]
+    fn fold<Acc, F>(mut self, init: Acc, f: F) -> Acc
+    where
+        F: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.as_mut().fold(init, f)
+    }
+}
+
+impl<T, B> DoubleEndedIterator for RefMut<T, B>
+where
+    T: ?Sized + DoubleEndedIterator,
+    B: IsBrand,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.as_mut().next_back()
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.as_mut().nth_back(n)
+    }
+
+    #[inline]
+    fn rfold<Acc, F>(mut self, init: Acc, f: F) -> Acc
+    where
+        F: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.as_mut().rfold(init, f)
+    }
+}
+
+impl<T, B> ExactSizeIterator for RefMut<T, B>
+where
+    T: ?Sized + ExactSizeIterator,
+    B: IsBrand,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.as_ref().len()
+    }
+}
+
+impl<T, B> FusedIterator for RefMut<T, B>
+where
+    T: ?Sized + FusedIterator,
+    B: IsBrand,
+{
+}
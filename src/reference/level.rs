@@ -0,0 +1,85 @@
+//! Traits for splitting and joining references generically over their level
+
+use super::{parent_level, Ref};
+use crate::brand::IsBrand;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+impl<T, B, const LEVEL: usize> sealed::Sealed for Ref<T, B, LEVEL>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+}
+
+/// References which can be split, i.e. all [`Ref`]s. Allows generic code to split references of
+/// any level without spelling out `where [(); LEVEL + 1]:` bounds:
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// use std::ops::Deref;
+///
+/// // Reads the value through both halves and gives the reference back
+/// fn sum_halves<R>(reference: R) -> (i32, R)
+/// where
+///     R: CanSplit,
+///     R::Half: CanJoin<Joined = R> + Deref<Target = i32>,
+/// {
+///     let [left, right] = reference.split();
+///     let sum = *left + *right;
+///     (sum, left.join(right))
+/// }
+///
+/// let (husk, reference) = borrow!(heap!(5));
+/// let (sum, reference) = sum_halves(reference);
+/// assert_eq!(sum, 10);
+/// let [left, right] = reference.split();
+/// let (sum, left) = sum_halves(left);
+/// assert_eq!(sum, 10);
+/// let owned = left.join(right).reconstruct(husk);
+/// ```
+pub trait CanSplit: sealed::Sealed + Sized {
+    /// Reference with the incremented level. It can always be joined back, but generic code has
+    /// to require `Half: CanJoin<Joined = Self>`, since the compiler can't prove it.
+    type Half;
+
+    /// Split this reference into two, same as [`Ref::split`]
+    fn split(self) -> [Self::Half; 2];
+}
+
+/// References which can be joined, i.e. [`Ref`]s of non-zero level. See [`CanSplit`].
+pub trait CanJoin: sealed::Sealed + Sized {
+    /// Reference with the decremented level
+    type Joined;
+
+    /// Join this reference with another one, same as [`Ref::join`]
+    fn join(self, other: Self) -> Self::Joined;
+}
+
+impl<T, B, const LEVEL: usize> CanSplit for Ref<T, B, LEVEL>
+where
+    T: ?Sized,
+    B: IsBrand,
+    [(); LEVEL + 1]:,
+{
+    type Half = Ref<T, B, { LEVEL + 1 }>;
+
+    fn split(self) -> [Self::Half; 2] {
+        Ref::split(self)
+    }
+}
+
+impl<T, B, const LEVEL: usize> CanJoin for Ref<T, B, LEVEL>
+where
+    T: ?Sized,
+    B: IsBrand,
+    [(); parent_level(LEVEL)]:,
+{
+    type Joined = Ref<T, B, { parent_level(LEVEL) }>;
+
+    fn join(self, other: Self) -> Self::Joined {
+        Ref::join(self, other)
+    }
+}
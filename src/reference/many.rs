@@ -0,0 +1,174 @@
+//! Mutable references to many elements of a slice at once
+
+use std::{error::Error, fmt, marker::PhantomData, ptr::NonNull};
+
+use super::{Invariant, Ref, RefMut, Reunite};
+use crate::brand::{Element, IsBrand};
+
+/// The rest of a slice after borrowing some of its elements with
+/// [`.get_many_mut()`](Ref::get_many_mut). Required to reunite the elements.
+pub struct Remainder<T, B, const K: usize>
+where
+    B: IsBrand,
+{
+    ptr: NonNull<[T]>,
+    variance: Invariant<T>,
+    indices: [usize; K],
+    brand: B,
+}
+
+/// Indices given to [`.get_many_mut()`](Ref::get_many_mut) are out of bounds or overlapping.
+/// Contains the original reference.
+pub struct GetManyError<T, B>
+where
+    B: IsBrand,
+{
+    reference: RefMut<[T], B>,
+    overlapping: bool,
+}
+
+impl<T, B> GetManyError<T, B>
+where
+    B: IsBrand,
+{
+    /// Obtain the original reference back
+    #[must_use]
+    pub fn into_reference(self) -> RefMut<[T], B> {
+        self.reference
+    }
+
+    /// Whether some of the indices were equal, as opposed to out of bounds
+    #[must_use]
+    pub fn is_overlapping(&self) -> bool {
+        self.overlapping
+    }
+}
+
+impl<T, B> fmt::Debug for GetManyError<T, B>
+where
+    B: IsBrand,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GetManyError")
+            .field("overlapping", &self.overlapping)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, B> fmt::Display for GetManyError<T, B>
+where
+    B: IsBrand,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.overlapping {
+            f.write_str("indices are overlapping")
+        } else {
+            f.write_str("index is out of bounds")
+        }
+    }
+}
+
+impl<T, B> Error for GetManyError<T, B> where B: IsBrand {}
+
+impl<T, B> RefMut<[T], B>
+where
+    B: IsBrand,
+{
+    /// Split this reference into mutable references to elements at `indices`, which can be used
+    /// at the same time. The elements can be joined back by reuniting them with the returned
+    /// [`Remainder`].
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap_slice![1, 2, 3, 4]);
+    /// let Ok(([mut first, mut second], remainder)) = reference.get_many_mut([3, 0]) else {
+    ///     unreachable!()
+    /// };
+    /// std::mem::swap(&mut *first, &mut *second);
+    /// let owned = ([first, second], remainder).reunite().reconstruct(husk);
+    /// assert_eq!(owned.into_vec(), [4, 2, 3, 1]);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`GetManyError`] with the original reference if some of the indices are out of
+    /// bounds or equal.
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap_slice![1, 2, 3, 4]);
+    /// let Err(error) = reference.get_many_mut([1, 1]) else {
+    ///     unreachable!()
+    /// };
+    /// assert!(error.is_overlapping());
+    /// let Err(error) = error.into_reference().get_many_mut([1, 4]) else {
+    ///     unreachable!()
+    /// };
+    /// assert!(!error.is_overlapping());
+    /// let owned = error.into_reference().reconstruct(husk);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn get_many_mut<const K: usize>(
+        self,
+        indices: [usize; K],
+    ) -> Result<([RefMut<T, Element<B>>; K], Remainder<T, B, K>), GetManyError<T, B>> {
+        let len = self.ptr.len();
+        if indices.iter().any(|&index| index >= len) {
+            return Err(GetManyError {
+                reference: self,
+                overlapping: false,
+            });
+        }
+        let overlapping = indices.iter().enumerate().any(|(position, index)| {
+            indices
+                .iter()
+                .skip(position + 1)
+                .any(|other| other == index)
+        });
+        if overlapping {
+            return Err(GetManyError {
+                reference: self,
+                overlapping: true,
+            });
+        }
+        let data = self.ptr.cast::<T>();
+        // SAFETY: every index is in bounds, and they're pairwise distinct, so elements are
+        // disjoint. The reference to the whole slice can only be obtained back by `.reunite()`,
+        // which checks that all of them are in place.
+        let elements = indices.map(|index| unsafe { Ref::new(data.add(index), Element::new()) });
+        Ok((
+            elements,
+            Remainder {
+                ptr: self.ptr,
+                variance: PhantomData,
+                indices,
+                brand: self.into_brand(),
+            },
+        ))
+    }
+}
+
+impl<T, B, const K: usize> Reunite for ([RefMut<T, Element<B>>; K], Remainder<T, B, K>)
+where
+    B: IsBrand,
+{
+    type Whole = RefMut<[T], B>;
+
+    /// # Panics
+    /// Panics if the elements are not the ones borrowed with the remainder, in order.
+    fn reunite(self) -> Self::Whole {
+        let (elements, remainder) = self;
+        let data = remainder.ptr.cast::<T>().as_ptr();
+        assert!(
+            elements
+                .iter()
+                .zip(remainder.indices)
+                .all(|(element, index)| element.is_at(data.wrapping_add(index))),
+            "element references are not in place"
+        );
+        // SAFETY: all elements are consumed and were in place, so the slice is exclusively
+        // borrowed again by the reference with the original brand
+        unsafe { Ref::new(remainder.ptr, remainder.brand) }
+    }
+}
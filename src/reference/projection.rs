@@ -0,0 +1,143 @@
+//! Projecting a mutable reference to shared references to two fields at once
+
+use std::{
+    marker::PhantomData,
+    ptr::{self, NonNull},
+};
+
+use super::{Invariant, Ref, RefMut, Reunite};
+use crate::brand::{Field, IsBrand};
+
+/// The right to obtain the mutable reference back from references created by
+/// [`split_project!`](crate::split_project!), by [reuniting](Reunite::reunite) them
+pub struct Projected<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    ptr: NonNull<T>,
+    variance: Invariant<T>,
+    first: NonNull<()>,
+    second: NonNull<()>,
+    brand: B,
+}
+
+impl<T, B> RefMut<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    /// An implementation detail of the [`split_project!`](crate::split_project!) macro
+    ///
+    /// # Safety
+    /// `first` and `second` must only project the pointer they're given to a field.
+    ///
+    /// # Panics
+    /// Panics if a field is reached through [`Deref`](std::ops::Deref) into some other memory.
+    #[doc(hidden)]
+    #[allow(clippy::type_complexity)]
+    pub unsafe fn split_project<U, V>(
+        self,
+        first: impl FnOnce(*const T) -> *const U,
+        second: impl FnOnce(*const T) -> *const V,
+    ) -> (
+        Ref<U, Field<B, 0>, 1>,
+        Ref<V, Field<B, 1>, 1>,
+        Projected<T, B>,
+    ) {
+        // SAFETY: the references to the whole value are immediately projected to the fields, as
+        // guaranteed by the caller, and the mutable reference is only given back by reuniting
+        let (first, second) = unsafe {
+            (
+                Ref::<T, Field<B, 0>, 1>::new(self.ptr, Field::new()).project_field(first),
+                Ref::<T, Field<B, 1>, 1>::new(self.ptr, Field::new()).project_field(second),
+            )
+        };
+        let projected = Projected {
+            ptr: self.ptr,
+            variance: PhantomData,
+            first: first.ptr.cast(),
+            second: second.ptr.cast(),
+            brand: self.into_brand(),
+        };
+        (first, second, projected)
+    }
+}
+
+impl<T, U, V, B> Reunite
+    for (
+        Ref<U, Field<B, 0>, 1>,
+        Ref<V, Field<B, 1>, 1>,
+        Projected<T, B>,
+    )
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    type Whole = RefMut<T, B>;
+
+    /// # Panics
+    /// Panics if the field references were projected from another value, which is only possible
+    /// for references to different elements branded with [`Element`](crate::brand::Element).
+    fn reunite(self) -> Self::Whole {
+        let (first, second, projected) = self;
+        assert!(
+            ptr::addr_eq(first.ptr.as_ptr(), projected.first.as_ptr())
+                && ptr::addr_eq(second.ptr.as_ptr(), projected.second.as_ptr()),
+            "field references belong to different values"
+        );
+        // SAFETY: both field references are consumed, so the value is exclusively borrowed again
+        // by the reference with the original brand
+        unsafe { Ref::new(projected.ptr, projected.brand) }
+    }
+}
+
+/// Turn a [`RefMut`] into shared references to two of its fields at once, without sharing the
+/// whole value. Evaluates to the field references and a [`Projected`] token, which can be
+/// [reunited](Reunite::reunite) with them to obtain the mutable reference back.
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// struct World {
+///     physics: Vec<f32>,
+///     render: (u32, String),
+/// }
+///
+/// fn simulate(physics: &[f32]) -> f32 {
+///     physics.iter().sum()
+/// }
+///
+/// let world = heap!(World { physics: vec![1.0, 2.0], render: (0, String::from("frame")) });
+/// let (husk, reference) = borrow!(world);
+/// let (physics, name, projected) = split_project!(reference => .physics, .render.1);
+/// assert_eq!(simulate(&physics), 3.0);
+/// assert_eq!(*name, "frame");
+/// let mut reference = (physics, name, projected).reunite();
+/// reference.render.0 += 1;
+/// let owned = reference.reconstruct(husk);
+/// ```
+///
+/// The field references are shared:
+/// ```compile_fail
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// # struct Point { x: i32, y: i32 }
+/// let (husk, reference) = borrow!(heap!(Point { x: 1, y: 2 }));
+/// let (mut x, y, projected) = split_project!(reference => .x, .y);
+/// *x += 1;
+/// ```
+#[macro_export]
+macro_rules! split_project {
+    ($reference:expr => $(.$first:tt)+, $(.$second:tt)+) => {{
+        let reference = $reference;
+        // SAFETY: we're only projecting to fields
+        unsafe {
+            $crate::Ref::split_project(
+                reference,
+                |ptr| ::core::ptr::addr_of!((*ptr)$(.$first)+),
+                |ptr| ::core::ptr::addr_of!((*ptr)$(.$second)+),
+            )
+        }
+    }};
+}
@@ -0,0 +1,56 @@
+//! Serializing and deserializing referenced values with [`serde`]
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{Ref, RefMut};
+use crate::brand::IsBrand;
+
+/// Serializes the value, like `&T` does
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// let (husk, reference) = borrow!(heap!(vec![1, 2]));
+/// let [left, right] = reference.split();
+/// assert_eq!(serde_json::to_string(&left).unwrap(), "[1,2]");
+/// # left.join(right).reconstruct(husk);
+/// ```
+impl<T, B, const LEVEL: usize> Serialize for Ref<T, B, LEVEL>
+where
+    T: ?Sized + Serialize,
+    B: IsBrand,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        T::serialize(self.as_ref(), serializer)
+    }
+}
+
+impl<T, B> RefMut<T, B>
+where
+    B: IsBrand,
+{
+    /// Deserialize a new value over the referenced one. Values which support it, like [`Vec`],
+    /// reuse their allocations instead of making new ones:
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, mut numbers) = borrow!(heap!(Vec::with_capacity(16)));
+    /// numbers.extend([4, 5]);
+    /// let mut deserializer = serde_json::Deserializer::from_str("[1, 2, 3]");
+    /// numbers.deserialize_in_place(&mut deserializer).unwrap();
+    /// assert_eq!(*numbers, [1, 2, 3]);
+    /// assert!(numbers.capacity() >= 16);
+    /// # numbers.reconstruct(husk);
+    /// ```
+    pub fn deserialize_in_place<'de, D>(&mut self, deserializer: D) -> Result<(), D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        T::deserialize_in_place(deserializer, self.as_mut())
+    }
+}
@@ -0,0 +1,226 @@
+//! Sharing a reference between a number of handles known only at runtime
+
+use std::{
+    marker::PhantomData,
+    ops::Deref,
+    ptr::NonNull,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use super::{Invariant, Ref, RefMut};
+use crate::brand::IsBrand;
+
+/// Shared handle to a value created by [`RefMut::split_dyn`]. Dropping it or giving it to
+/// [`Collector::absorb`] allows the [`Collector`] to reclaim the mutable reference.
+pub struct SharedRef<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    ptr: NonNull<T>,
+    variance: Invariant<T>,
+    outstanding: Arc<AtomicUsize>,
+    brand: PhantomData<B>,
+}
+
+/// The right to obtain the mutable reference back from [`SharedRef`]s, see
+/// [`RefMut::split_dyn`]
+pub struct Collector<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    ptr: NonNull<T>,
+    variance: Invariant<T>,
+    outstanding: Arc<AtomicUsize>,
+    brand: B,
+}
+
+impl<T, B> RefMut<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    /// Split this reference into `n` shared handles, counting the handles at runtime instead of
+    /// tracking the level in the type. The mutable reference can be reclaimed from the returned
+    /// [`Collector`] once all the handles are gone.
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let clients = 3;
+    /// let (husk, reference) = borrow!(heap!(String::from("motd")));
+    /// let (mut handles, collector) = reference.split_dyn(clients);
+    /// assert!(handles.iter().all(|handle| **handle == "motd"));
+    ///
+    /// // Handles can be dropped or absorbed in any order
+    /// let last = handles.pop().unwrap();
+    /// let first = handles.remove(0);
+    /// drop(last);
+    /// let collector = collector.try_reclaim().err().unwrap();
+    /// collector.absorb(first);
+    /// drop(handles);
+    /// let mut reference = collector.try_reclaim().ok().unwrap();
+    /// reference.push('!');
+    /// assert_eq!(reference.reconstruct(husk).into_inner(), "motd!");
+    /// ```
+    #[must_use]
+    pub fn split_dyn(self, n: usize) -> (Vec<SharedRef<T, B>>, Collector<T, B>) {
+        let outstanding = Arc::new(AtomicUsize::new(n));
+        let handles = (0..n)
+            .map(|_| SharedRef {
+                ptr: self.ptr,
+                variance: PhantomData,
+                outstanding: Arc::clone(&outstanding),
+                brand: PhantomData,
+            })
+            .collect();
+        let collector = Collector {
+            ptr: self.ptr,
+            variance: PhantomData,
+            outstanding,
+            brand: self.into_brand(),
+        };
+        (handles, collector)
+    }
+}
+
+impl<T, B> Collector<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    /// Number of handles which still exist
+    #[must_use]
+    pub fn outstanding(&self) -> usize {
+        self.outstanding.load(Ordering::Acquire)
+    }
+
+    /// Give a handle back. Same as dropping it, but checks that it belongs to this collector.
+    ///
+    /// # Panics
+    /// Panics if the handle was created by another [`RefMut::split_dyn`] call.
+    ///
+    /// ```should_panic
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap_slice![1, 2]);
+    /// let (mut chunks, _) = reference.chunks_mut(1);
+    /// let (_, second) = chunks.pop().unwrap().split_dyn(0);
+    /// let (mut handles, _) = chunks.pop().unwrap().split_dyn(1);
+    /// // Both chunks have the same brand
+    /// second.absorb(handles.pop().unwrap());
+    /// ```
+    // The handle must be consumed, so it can't be used after it's counted
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn absorb(&self, handle: SharedRef<T, B>) {
+        assert!(
+            Arc::ptr_eq(&self.outstanding, &handle.outstanding),
+            "handle belongs to another collector"
+        );
+    }
+
+    /// Obtain the mutable reference back if all the handles are gone
+    ///
+    /// # Errors
+    /// Gives the collector back if some handles still exist.
+    pub fn try_reclaim(self) -> Result<RefMut<T, B>, Self> {
+        if self.outstanding() == 0 {
+            // SAFETY: all the shared handles are gone, and their accesses happened before the
+            // `Acquire` load of the counter
+            Ok(unsafe { Ref::new(self.ptr, self.brand) })
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl<T, B> Clone for SharedRef<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    fn clone(&self) -> Self {
+        self.outstanding.fetch_add(1, Ordering::Relaxed);
+        Self {
+            ptr: self.ptr,
+            variance: PhantomData,
+            outstanding: Arc::clone(&self.outstanding),
+            brand: PhantomData,
+        }
+    }
+}
+
+impl<T, B> Drop for SharedRef<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    fn drop(&mut self) {
+        self.outstanding.fetch_sub(1, Ordering::Release);
+    }
+}
+
+impl<T, B> Deref for SharedRef<T, B>
+where
+    T: ?Sized,
+    B: IsBrand,
+{
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: the mutable reference can't be reclaimed while this handle exists
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+/// Handles can be given to other threads like `&T`:
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// use std::thread;
+///
+/// let (husk, reference) = borrow!(heap!(String::from("motd")));
+/// let (handles, collector) = reference.split_dyn(2);
+/// thread::scope(|scope| {
+///     for handle in handles {
+///         scope.spawn(move || assert_eq!(*handle, "motd"));
+///     }
+/// });
+/// let owned = collector.try_reclaim().ok().unwrap().reconstruct(husk);
+/// ```
+// SAFETY: handles only give out `&T`, and the counter is atomic
+unsafe impl<T, B> Send for SharedRef<T, B>
+where
+    T: ?Sized + Sync,
+    B: IsBrand,
+{
+}
+
+// SAFETY: same as above
+unsafe impl<T, B> Sync for SharedRef<T, B>
+where
+    T: ?Sized + Sync,
+    B: IsBrand,
+{
+}
+
+// SAFETY: the collector gives out the mutable reference, which is sent like `&mut T`
+unsafe impl<T, B> Send for Collector<T, B>
+where
+    T: ?Sized + Send,
+    B: IsBrand + Send,
+{
+}
+
+// SAFETY: `&Collector` never accesses the value
+unsafe impl<T, B> Sync for Collector<T, B>
+where
+    T: ?Sized,
+    B: IsBrand + Sync,
+{
+}
@@ -0,0 +1,644 @@
+//! Splitting references to slices into disjoint parts
+
+use std::{cell::Cell, marker::PhantomData, ptr::NonNull};
+
+use super::{parent_level, Invariant, Ref, RefMut, Reunite};
+use crate::brand::{Element, Field, IsBrand};
+
+impl<T, B> RefMut<[T], B>
+where
+    B: IsBrand,
+{
+    /// Split this reference into two disjoint mutable references at index `mid`, like
+    /// [`slice::split_at_mut`]. The halves can be joined back with [`Ref::join_at`].
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap_slice![0u8; 5]);
+    /// let (mut header, mut body) = reference.split_at(2);
+    /// // Both halves are mutable at the same time
+    /// header.fill(1);
+    /// body.fill(2);
+    /// let owned = RefMut::join_at(header, body).reconstruct(husk);
+    /// assert_eq!(owned.into_vec(), [1, 1, 2, 2, 2]);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `mid > len`.
+    ///
+    /// ```should_panic
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap_slice![0u8; 5]);
+    /// let (header, body) = reference.split_at(6);
+    /// ```
+    #[must_use]
+    #[allow(clippy::type_complexity)]
+    pub fn split_at(self, mid: usize) -> (RefMut<[T], Field<B, 0>>, RefMut<[T], Field<B, 1>>) {
+        let len = self.ptr.len();
+        assert!(mid <= len, "mid > len");
+        let data = self.ptr.cast::<T>();
+        // SAFETY: `mid <= len`, so the pointer stays within the slice
+        let right = unsafe { data.add(mid) };
+        let left = NonNull::slice_from_raw_parts(data, mid);
+        let right = NonNull::slice_from_raw_parts(right, len - mid);
+        // SAFETY: the halves are disjoint and get distinct brands, and the reference to the whole
+        // slice can only be obtained back by `.join_at()`, which consumes both of them
+        unsafe { (Ref::new(left, Field::new()), Ref::new(right, Field::new())) }
+    }
+
+    /// Join the halves produced by [`.split_at()`](Ref::split_at) back into a reference to the
+    /// whole slice. Halves of different slices can't be joined:
+    ///
+    /// ```compile_fail
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk1, reference1) = borrow!(heap_slice![0u8; 5]);
+    /// let (husk2, reference2) = borrow!(heap_slice![0u8; 5]);
+    /// let (left1, _) = reference1.split_at(2);
+    /// let (_, right2) = reference2.split_at(2);
+    /// let reference = RefMut::join_at(left1, right2);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the halves are not adjacent, which can only happen if one of them was narrowed
+    /// with [`.map_unchecked()`](Ref::map_unchecked).
+    #[must_use]
+    // The halves must be consumed, so they can't be used after joining
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn join_at(left: RefMut<[T], Field<B, 0>>, right: RefMut<[T], Field<B, 1>>) -> Self {
+        let data = left.ptr.cast::<T>();
+        assert!(
+            data.as_ptr().wrapping_add(left.ptr.len()) == right.ptr.cast::<T>().as_ptr(),
+            "slices are not adjacent"
+        );
+        let ptr = NonNull::slice_from_raw_parts(data, left.ptr.len() + right.ptr.len());
+        // SAFETY: both halves are consumed, so the whole slice is exclusively borrowed again by
+        // the reference with the original brand
+        unsafe { Ref::new(ptr, B::conjure()) }
+    }
+}
+
+/// Whether `element` points to one of the elements of `slice`
+fn contains_element<T>(slice: NonNull<[T]>, element: NonNull<T>) -> bool {
+    let offset = element.addr().get().wrapping_sub(slice.addr().get());
+    match size_of::<T>() {
+        0 => offset == 0 && !slice.is_empty(),
+        size => offset < slice.len() * size && offset.is_multiple_of(size),
+    }
+}
+
+impl<T, B, const LEVEL: usize> Ref<[T], B, LEVEL>
+where
+    B: IsBrand,
+{
+    /// Split this reference into a shared reference to the element at `index` and a shared
+    /// reference to the whole slice, or give it back if `index` is out of bounds. The pair can
+    /// be joined back with [`.join_element()`](Ref::join_element).
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap_slice![1, 2, 3]);
+    /// let Err(reference) = reference.get(3) else {
+    ///     unreachable!()
+    /// };
+    /// let Ok((element, slice)) = reference.get(1) else {
+    ///     unreachable!()
+    /// };
+    /// assert_eq!(*element, 2);
+    /// assert_eq!(slice.len(), 3);
+    /// let owned = slice.join_element(element).reconstruct(husk);
+    /// ```
+    // The whole reference is given back on error
+    #[allow(clippy::type_complexity)]
+    pub fn get(
+        self,
+        index: usize,
+    ) -> Result<(Ref<T, B, { LEVEL + 1 }>, Ref<[T], B, { LEVEL + 1 }>), Self> {
+        if index >= self.ptr.len() {
+            return Err(self);
+        }
+        // SAFETY: `index` is in bounds
+        let element = unsafe { self.ptr.cast::<T>().add(index) };
+        // SAFETY: we're using `.duplicate()` to split a reference
+        let ptr = self.ptr;
+        let (brand1, brand2) = unsafe { self.into_brand().duplicate() };
+        // SAFETY: we're splitting `Ref` while increasing level, and the element is a part of the
+        // slice
+        unsafe { Ok((Ref::new(element, brand1), Ref::new(ptr, brand2))) }
+    }
+
+    /// Join the reference to the whole slice with a reference to its element returned by
+    /// [`.get()`](Ref::get), decrementing level
+    ///
+    /// # Panics
+    /// Panics if `element` doesn't point into this slice, which is only possible for references
+    /// to different elements branded with [`Element`](crate::brand::Element).
+    // The element reference must be consumed, so it can't be used after joining
+    #[allow(clippy::needless_pass_by_value)]
+    #[must_use]
+    pub fn join_element(self, element: Ref<T, B, LEVEL>) -> Ref<[T], B, { parent_level(LEVEL) }> {
+        assert!(
+            contains_element(self.ptr, element.ptr),
+            "element doesn't belong to the slice"
+        );
+        // SAFETY: we're joining two `Ref`s of the same value while decreasing level by one
+        unsafe { Ref::new(self.ptr, self.into_brand()) }
+    }
+}
+
+impl<T, B> RefMut<[T], B>
+where
+    B: IsBrand,
+{
+    /// Split this reference into mutable references to the elements before `index`, the
+    /// element at `index` and the elements after it, or give it back if `index` is out of
+    /// bounds. The parts can be joined back with [`.reunite()`](Reunite::reunite).
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap_slice![1, 2, 3, 4]);
+    /// let Err(reference) = reference.get_mut(4) else {
+    ///     unreachable!()
+    /// };
+    /// let Ok((prefix, mut element, suffix)) = reference.get_mut(1) else {
+    ///     unreachable!()
+    /// };
+    /// *element = prefix[0] + suffix.iter().sum::<i32>();
+    /// let owned = (prefix, element, suffix).reunite().reconstruct(husk);
+    /// assert_eq!(owned.into_vec(), [1, 8, 3, 4]);
+    /// ```
+    // The whole reference is given back on error
+    #[allow(clippy::type_complexity)]
+    pub fn get_mut(
+        self,
+        index: usize,
+    ) -> Result<
+        (
+            RefMut<[T], Field<B, 0>>,
+            RefMut<T, Field<B, 1>>,
+            RefMut<[T], Field<B, 2>>,
+        ),
+        Self,
+    > {
+        let len = self.ptr.len();
+        if index >= len {
+            return Err(self);
+        }
+        let data = self.ptr.cast::<T>();
+        // SAFETY: `index` is in bounds
+        let element = unsafe { data.add(index) };
+        // SAFETY: `index + 1 <= len`, so the pointer stays within the slice
+        let suffix = unsafe { element.add(1) };
+        let prefix = NonNull::slice_from_raw_parts(data, index);
+        let suffix = NonNull::slice_from_raw_parts(suffix, len - index - 1);
+        // SAFETY: the parts are disjoint and get distinct brands, and the reference to the whole
+        // slice can only be obtained back by `.reunite()`, which consumes all of them
+        unsafe {
+            Ok((
+                Ref::new(prefix, Field::new()),
+                Ref::new(element, Field::new()),
+                Ref::new(suffix, Field::new()),
+            ))
+        }
+    }
+}
+
+impl<T, B> Reunite
+    for (
+        RefMut<[T], Field<B, 0>>,
+        RefMut<T, Field<B, 1>>,
+        RefMut<[T], Field<B, 2>>,
+    )
+where
+    B: IsBrand,
+{
+    type Whole = RefMut<[T], B>;
+
+    /// # Panics
+    /// Panics if the parts are not adjacent, which is only possible for parts of different
+    /// elements branded with [`Element`](crate::brand::Element).
+    fn reunite(self) -> Self::Whole {
+        let (prefix, element, suffix) = self;
+        let data = prefix.ptr.cast::<T>();
+        let index = prefix.ptr.len();
+        assert!(
+            element.is_at(data.as_ptr().wrapping_add(index))
+                && suffix.ptr.cast::<T>().as_ptr() == data.as_ptr().wrapping_add(index + 1),
+            "slice parts are not adjacent"
+        );
+        let ptr = NonNull::slice_from_raw_parts(data, index + 1 + suffix.ptr.len());
+        // SAFETY: all parts are consumed, so the whole slice is exclusively borrowed again by the
+        // reference with the original brand
+        unsafe { Ref::new(ptr, B::conjure()) }
+    }
+}
+
+/// Iterator over mutable references to elements of a slice, returned by
+/// [`.into_iter_mut()`](Ref::into_iter_mut)
+pub struct IntoIterMut<T, B>
+where
+    B: IsBrand,
+{
+    data: NonNull<T>,
+    variance: Invariant<T>,
+    len: usize,
+    next: usize,
+    brand: B,
+}
+
+impl<T, B> RefMut<[T], B>
+where
+    B: IsBrand,
+{
+    /// Consume this reference, iterating over mutable references to every element. All of them
+    /// can be used at the same time, and the reference to the whole slice can be obtained back
+    /// with [`.finish()`](IntoIterMut::finish) once all of them are given back.
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap_slice![1, 2, 3]);
+    /// let mut iter = reference.into_iter_mut();
+    /// let mut elements: Vec<_> = iter.by_ref().collect();
+    /// for element in &mut elements {
+    ///     **element *= 10;
+    /// }
+    /// let owned = iter.finish(elements).reconstruct(husk);
+    /// assert_eq!(owned.into_vec(), [10, 20, 30]);
+    /// ```
+    #[must_use]
+    pub fn into_iter_mut(self) -> IntoIterMut<T, B> {
+        IntoIterMut {
+            data: self.ptr.cast(),
+            variance: PhantomData,
+            len: self.ptr.len(),
+            next: 0,
+            brand: self.into_brand(),
+        }
+    }
+}
+
+impl<T, B> IntoIterMut<T, B>
+where
+    B: IsBrand,
+{
+    /// Obtain the reference to the whole slice back, consuming the references to elements
+    /// returned by this iterator so far. Elements which weren't iterated over yet are given back
+    /// implicitly.
+    ///
+    /// # Panics
+    /// Panics if `elements` are not exactly the elements returned by this iterator, in order.
+    ///
+    /// ```should_panic
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap_slice![1, 2, 3]);
+    /// let mut iter = reference.into_iter_mut();
+    /// let first = iter.next().unwrap();
+    /// let second = iter.next().unwrap();
+    /// let reference = iter.finish([second, first]);
+    /// ```
+    pub fn finish(
+        self,
+        elements: impl IntoIterator<Item = RefMut<T, Element<B>>>,
+    ) -> RefMut<[T], B> {
+        let mut count = 0;
+        for element in elements {
+            assert!(
+                count < self.next && element.is_at(self.data.as_ptr().wrapping_add(count)),
+                "element references are not in place"
+            );
+            count += 1;
+        }
+        assert_eq!(
+            count, self.next,
+            "not all element references are given back"
+        );
+        let ptr = NonNull::slice_from_raw_parts(self.data, self.len);
+        // SAFETY: all returned elements are consumed and were in place, so the slice is
+        // exclusively borrowed again by the reference with the original brand
+        unsafe { Ref::new(ptr, self.brand) }
+    }
+}
+
+impl<T, B> Iterator for IntoIterMut<T, B>
+where
+    B: IsBrand,
+{
+    type Item = RefMut<T, Element<B>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == self.len {
+            return None;
+        }
+        // SAFETY: `self.next < self.len`, so the element is in bounds
+        let element = unsafe { self.data.add(self.next) };
+        self.next += 1;
+        // SAFETY: every element is returned once, and the reference to the whole slice can only be
+        // obtained back by `.finish()`, which checks that all of them are in place
+        Some(unsafe { Ref::new(element, Element::new()) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len - self.next;
+        (len, Some(len))
+    }
+}
+
+impl<T, B> ExactSizeIterator for IntoIterMut<T, B> where B: IsBrand {}
+
+/// The slice split by [`.chunks_mut()`](Ref::chunks_mut). Required to reunite the chunks.
+pub struct Chunks<T, B>
+where
+    B: IsBrand,
+{
+    ptr: NonNull<[T]>,
+    variance: Invariant<T>,
+    brand: B,
+}
+
+impl<T, B> RefMut<[T], B>
+where
+    B: IsBrand,
+{
+    /// Split this reference into mutable references to chunks of `chunk_size` elements, like
+    /// [`slice::chunks_mut`]. The last chunk is shorter if `chunk_size` doesn't divide the length.
+    /// The chunks can be joined back with [`.reunite()`](Reunite::reunite), together with the
+    /// returned [`Chunks`].
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// # use std::thread;
+    /// let (husk, reference) = borrow!(heap_slice![1u64; 10]);
+    /// let (chunks, whole) = reference.chunks_mut(4);
+    /// assert_eq!(chunks.len(), 3);
+    /// let workers: Vec<_> = chunks
+    ///     .into_iter()
+    ///     .enumerate()
+    ///     .map(|(index, mut chunk)| {
+    ///         thread::spawn(move || {
+    ///             chunk.fill(index as u64);
+    ///             chunk
+    ///         })
+    ///     })
+    ///     .collect();
+    /// let chunks: Vec<_> = workers.into_iter().map(|worker| worker.join().unwrap()).collect();
+    /// let owned = (chunks, whole).reunite().reconstruct(husk);
+    /// assert_eq!(owned.into_vec(), [0, 0, 0, 0, 1, 1, 1, 1, 2, 2]);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is 0.
+    #[must_use]
+    #[allow(clippy::type_complexity)]
+    pub fn chunks_mut(self, chunk_size: usize) -> (Vec<RefMut<[T], Element<B>>>, Chunks<T, B>) {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        let data = self.ptr.cast::<T>();
+        let len = self.ptr.len();
+        let chunks = (0..len.div_ceil(chunk_size))
+            .map(|index| {
+                let start = index * chunk_size;
+                let chunk_len = chunk_size.min(len - start);
+                // SAFETY: `start < len`, so the pointer stays within the slice
+                let chunk = unsafe { data.add(start) };
+                let chunk = NonNull::slice_from_raw_parts(chunk, chunk_len);
+                // SAFETY: chunks are disjoint, and the reference to the whole slice can only be
+                // obtained back by `.reunite()`, which checks that all of them are in place
+                unsafe { Ref::new(chunk, Element::new()) }
+            })
+            .collect();
+        let whole = Chunks {
+            ptr: self.ptr,
+            variance: PhantomData,
+            brand: self.into_brand(),
+        };
+        (chunks, whole)
+    }
+}
+
+impl<T, B> Reunite for (Vec<RefMut<[T], Element<B>>>, Chunks<T, B>)
+where
+    B: IsBrand,
+{
+    type Whole = RefMut<[T], B>;
+
+    /// # Panics
+    /// Panics if the chunks don't cover the original slice exactly.
+    ///
+    /// ```should_panic
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap_slice![0u8; 10]);
+    /// let (mut chunks, whole) = reference.chunks_mut(4);
+    /// let last = chunks.pop().unwrap();
+    /// // Panics: the first two chunks are still borrowed
+    /// let reference = (vec![last], whole).reunite();
+    /// # reference.reconstruct(husk);
+    /// ```
+    fn reunite(self) -> Self::Whole {
+        let (chunks, whole) = self;
+        let data = whole.ptr.cast::<T>().as_ptr();
+        let mut len = 0;
+        for chunk in &chunks {
+            assert!(
+                chunk.ptr.cast::<T>().as_ptr() == data.wrapping_add(len),
+                "chunks don't cover the slice"
+            );
+            len += chunk.ptr.len();
+        }
+        assert!(len == whole.ptr.len(), "chunks don't cover the slice");
+        // SAFETY: the consumed chunks cover the whole slice, so it's exclusively borrowed again by
+        // the reference with the original brand
+        unsafe { Ref::new(whole.ptr, whole.brand) }
+    }
+}
+
+impl<T, B, const LEVEL: usize> Ref<[T], B, LEVEL>
+where
+    B: IsBrand,
+{
+    /// Same as [`.get(0)`](Ref::get)
+    #[allow(clippy::type_complexity)]
+    pub fn first(self) -> Result<(Ref<T, B, { LEVEL + 1 }>, Ref<[T], B, { LEVEL + 1 }>), Self> {
+        self.get(0)
+    }
+
+    /// Same as [`.get(len - 1)`](Ref::get)
+    #[allow(clippy::type_complexity)]
+    pub fn last(self) -> Result<(Ref<T, B, { LEVEL + 1 }>, Ref<[T], B, { LEVEL + 1 }>), Self> {
+        let len = self.ptr.len();
+        self.get(len.wrapping_sub(1))
+    }
+
+    /// Split this reference into references to the first element and the rest of the slice,
+    /// keeping the level, or give it back if the slice is empty. The parts can be joined back
+    /// with [`.reunite()`](Reunite::reunite).
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap_slice![1, 2, 3]);
+    /// let Ok((mut first, rest)) = reference.split_first() else {
+    ///     unreachable!()
+    /// };
+    /// *first = rest.iter().sum();
+    /// let owned = (first, rest).reunite().reconstruct(husk);
+    /// assert_eq!(owned.into_vec(), [5, 2, 3]);
+    ///
+    /// let (husk, reference) = borrow!(heap_slice![0; 0]);
+    /// let Err(reference) = reference.split_first() else {
+    ///     unreachable!()
+    /// };
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn split_first(
+        self,
+    ) -> Result<(Ref<T, Field<B, 0>, LEVEL>, Ref<[T], Field<B, 1>, LEVEL>), Self> {
+        let len = self.ptr.len();
+        if len == 0 {
+            return Err(self);
+        }
+        let first = self.ptr.cast::<T>();
+        // SAFETY: the slice isn't empty, so the pointer stays within it
+        let rest = unsafe { first.add(1) };
+        let rest = NonNull::slice_from_raw_parts(rest, len - 1);
+        // SAFETY: the parts are disjoint and get distinct brands with the same level, and the
+        // reference to the whole slice can only be obtained back by `.reunite()`, which consumes
+        // both of them
+        unsafe { Ok((Ref::new(first, Field::new()), Ref::new(rest, Field::new()))) }
+    }
+
+    /// Split this reference into references to the last element and the rest of the slice,
+    /// keeping the level, or give it back if the slice is empty. The parts can be joined back
+    /// with [`.reunite()`](Reunite::reunite).
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, reference) = borrow!(heap_slice![1, 2, 3]);
+    /// let [ref1, ref2] = reference.split();
+    /// let Ok((rest, last)) = ref1.split_last() else {
+    ///     unreachable!()
+    /// };
+    /// assert_eq!(*last, 3);
+    /// assert_eq!(*rest, [1, 2]);
+    /// let owned = (rest, last).reunite().join(ref2).reconstruct(husk);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn split_last(
+        self,
+    ) -> Result<(Ref<[T], Field<B, 0>, LEVEL>, Ref<T, Field<B, 1>, LEVEL>), Self> {
+        let len = self.ptr.len();
+        if len == 0 {
+            return Err(self);
+        }
+        let data = self.ptr.cast::<T>();
+        // SAFETY: the slice isn't empty, so the last element is in bounds
+        let last = unsafe { data.add(len - 1) };
+        let rest = NonNull::slice_from_raw_parts(data, len - 1);
+        // SAFETY: the parts are disjoint and get distinct brands with the same level, and the
+        // reference to the whole slice can only be obtained back by `.reunite()`, which consumes
+        // both of them
+        unsafe { Ok((Ref::new(rest, Field::new()), Ref::new(last, Field::new()))) }
+    }
+}
+
+impl<T, B, const LEVEL: usize> Reunite
+    for (Ref<T, Field<B, 0>, LEVEL>, Ref<[T], Field<B, 1>, LEVEL>)
+where
+    B: IsBrand,
+{
+    type Whole = Ref<[T], B, LEVEL>;
+
+    /// # Panics
+    /// Panics if the parts are not adjacent, which is only possible for parts of different
+    /// elements branded with [`Element`](crate::brand::Element).
+    fn reunite(self) -> Self::Whole {
+        let (first, rest) = self;
+        let data = first.ptr;
+        assert!(
+            rest.ptr.cast::<T>().as_ptr() == data.as_ptr().wrapping_add(1),
+            "slice parts are not adjacent"
+        );
+        let ptr = NonNull::slice_from_raw_parts(data, rest.ptr.len() + 1);
+        // SAFETY: both parts are consumed, so the whole slice is borrowed again by the reference
+        // with the original brand and level
+        unsafe { Ref::new(ptr, B::conjure()) }
+    }
+}
+
+impl<T, B, const LEVEL: usize> Reunite
+    for (Ref<[T], Field<B, 0>, LEVEL>, Ref<T, Field<B, 1>, LEVEL>)
+where
+    B: IsBrand,
+{
+    type Whole = Ref<[T], B, LEVEL>;
+
+    /// # Panics
+    /// Panics if the parts are not adjacent, which is only possible for parts of different
+    /// elements branded with [`Element`](crate::brand::Element).
+    fn reunite(self) -> Self::Whole {
+        let (rest, last) = self;
+        let data = rest.ptr.cast::<T>();
+        let len = rest.ptr.len();
+        assert!(
+            last.is_at(data.as_ptr().wrapping_add(len)),
+            "slice parts are not adjacent"
+        );
+        let ptr = NonNull::slice_from_raw_parts(data, len + 1);
+        // SAFETY: both parts are consumed, so the whole slice is borrowed again by the reference
+        // with the original brand and level
+        unsafe { Ref::new(ptr, B::conjure()) }
+    }
+}
+
+impl<T, B> RefMut<[T], B>
+where
+    B: IsBrand,
+{
+    /// Swap the elements behind two mutable references to slices, like
+    /// [`slice::swap_with_slice`]
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk1, mut ref1) = borrow!(heap_slice![1, 2]);
+    /// let (husk2, mut ref2) = borrow!(heap_slice![3, 4]);
+    /// ref1.swap_with_slice(&mut ref2);
+    /// assert_eq!(ref1.reconstruct(husk1).into_vec(), [3, 4]);
+    /// assert_eq!(ref2.reconstruct(husk2).into_vec(), [1, 2]);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the slices have different lengths.
+    pub fn swap_with_slice<B2>(&mut self, other: &mut RefMut<[T], B2>)
+    where
+        B2: IsBrand,
+    {
+        (**self).swap_with_slice(other);
+    }
+
+    /// Access the elements through [`Cell`]s, like [`Cell::as_slice_of_cells`]
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// let (husk, mut reference) = borrow!(heap_slice![1, 2, 3]);
+    /// let cells = reference.as_slice_of_cells();
+    /// // Overlapping windows can't be mutable references, but they can be cells
+    /// for window in cells.windows(2) {
+    ///     window[1].set(window[0].get() + window[1].get());
+    /// }
+    /// assert_eq!(reference.reconstruct(husk).into_vec(), [1, 3, 6]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_slice_of_cells(&mut self) -> &[Cell<T>] {
+        self.as_cell().as_slice_of_cells()
+    }
+}
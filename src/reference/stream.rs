@@ -0,0 +1,111 @@
+//! Polling streams in place through mutable references
+
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::{FusedStream, Stream};
+
+use super::RefMut;
+use crate::{brand::IsBrand, Pinned};
+
+/// Polls an [`Unpin`] stream, like [`Future`](std::future::Future) is implemented for futures
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// # use nolife::*;
+/// use std::{
+///     pin::Pin,
+///     task::{Context, Poll, Waker},
+/// };
+///
+/// use futures_core::Stream;
+///
+/// // Counts down to zero
+/// struct Countdown(u32);
+///
+/// impl Stream for Countdown {
+///     type Item = u32;
+///
+///     fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<u32>> {
+///         Poll::Ready(self.0.checked_sub(1).inspect(|&n| self.0 = n))
+///     }
+///
+///     fn size_hint(&self) -> (usize, Option<usize>) {
+///         (self.0 as usize, Some(self.0 as usize))
+///     }
+/// }
+///
+/// let (husk, mut countdown) = borrow!(heap!(Countdown(2)));
+/// let mut context = Context::from_waker(Waker::noop());
+/// assert_eq!(countdown.size_hint(), (2, Some(2)));
+/// assert_eq!(Pin::new(&mut countdown).poll_next(&mut context), Poll::Ready(Some(1)));
+/// assert_eq!(Pin::new(&mut countdown).poll_next(&mut context), Poll::Ready(Some(0)));
+/// assert_eq!(Pin::new(&mut countdown).poll_next(&mut context), Poll::Ready(None));
+/// let owned = countdown.reconstruct(husk);
+/// ```
+impl<T, B> Stream for RefMut<T, B>
+where
+    T: ?Sized + Stream + Unpin,
+    B: IsBrand,
+{
+    type Item = T::Item;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // SAFETY: the reference is never moved out of, and pinning it doesn't pin the pointee
+        unsafe { self.get_unchecked_mut() }
+            .as_pin_mut()
+            .poll_next(cx)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.as_ref().size_hint()
+    }
+}
+
+impl<T, B> FusedStream for RefMut<T, B>
+where
+    T: ?Sized + FusedStream + Unpin,
+    B: IsBrand,
+{
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        self.as_ref().is_terminated()
+    }
+}
+
+/// Polls a stream created with [`heap_pin!`](crate::heap_pin!)
+impl<T, B> Stream for RefMut<Pinned<T>, B>
+where
+    T: Stream,
+    B: IsBrand,
+{
+    type Item = T::Item;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // SAFETY: the reference is never moved out of, and pinning it doesn't pin the pointee
+        unsafe { self.get_unchecked_mut() }
+            .pinned_mut()
+            .poll_next(cx)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.pinned().size_hint()
+    }
+}
+
+impl<T, B> FusedStream for RefMut<Pinned<T>, B>
+where
+    T: FusedStream,
+    B: IsBrand,
+{
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        self.pinned().is_terminated()
+    }
+}
@@ -0,0 +1,111 @@
+//! Sending and sharing references between threads
+//!
+//! A mutable reference can be sent to another thread when `&mut T` can, and shared references
+//! can be shared when `&T` can. Sending shared references also requires `T: Send`, since they
+//! can be joined back into a mutable reference on another thread.
+//!
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! use std::thread;
+//!
+//! let (husk, mut reference) = borrow!(heap!(vec![1, 2]));
+//! let reference = thread::spawn(move || {
+//!     reference.push(3);
+//!     reference
+//! })
+//! .join()
+//! .unwrap();
+//! let [left, right] = reference.split();
+//! let right = thread::scope(|scope| {
+//!     scope.spawn(|| assert_eq!(left.len(), 3));
+//!     scope.spawn(move || right).join().unwrap()
+//! });
+//! assert_eq!(left.join(right).reconstruct(husk).into_inner(), [1, 2, 3]);
+//! ```
+//!
+//! Owned values and husks are sent like the storage of their kind, e.g. like `Box<T>` for
+//! [`Heap`](crate::Heap):
+//! ```
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! use nolife::brand::{closure, IsBrand};
+//!
+//! fn assert_send<T: Send>() {}
+//! fn assert_sync<T: Sync>() {}
+//!
+//! fn assert_thread_safe<B: IsBrand + Send, F>() {
+//!     assert_send::<RefMut<Vec<u8>, B>>();
+//!     assert_sync::<RefMut<Vec<u8>, B>>();
+//!     assert_send::<Ref<Vec<u8>, B, 1>>();
+//!     assert_sync::<Ref<Vec<u8>, B, 1>>();
+//!     assert_send::<Owned<Vec<u8>, Heap>>();
+//!     assert_send::<Husk<Vec<u8>, B, Heap>>();
+//!     assert_send::<closure::Brand<F>>();
+//!     assert_sync::<closure::Brand<F>>();
+//! }
+//! ```
+//!
+//! Values which can't be sent, like [`Rc`](std::rc::Rc), can't be sent through references either:
+//! ```compile_fail
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! use std::rc::Rc;
+//!
+//! let (husk, reference) = borrow!(heap!(Rc::new(5)));
+//! std::thread::spawn(move || drop(reference));
+//! ```
+//!
+//! Neither can shared references to values which can be sent but not shared, like
+//! [`Cell`](std::cell::Cell):
+//! ```compile_fail
+//! # #![feature(generic_const_exprs)]
+//! # use nolife::*;
+//! use std::cell::Cell;
+//!
+//! let (husk, reference) = borrow!(heap!(Cell::new(5)));
+//! let [left, right] = reference.split();
+//! std::thread::spawn(move || drop(left));
+//! ```
+
+use super::Ref;
+use crate::brand::IsBrand;
+
+/// Whether a reference is mutable, used to require different bounds for sending mutable and
+/// shared references
+#[doc(hidden)]
+pub struct Access<const MUTABLE: bool>;
+
+/// Bounds which a value must satisfy for references to it to be [`Send`]
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "references to `{T}` cannot be sent between threads safely",
+    note = "mutable references require `{T}: Send`, and shared ones require `{T}: Send + Sync`"
+)]
+pub trait SendAccess<T>
+where
+    T: ?Sized,
+{
+}
+
+impl<T> SendAccess<T> for Access<true> where T: ?Sized + Send {}
+
+impl<T> SendAccess<T> for Access<false> where T: ?Sized + Send + Sync {}
+
+// SAFETY: a mutable reference gives the same access as `&mut T`, and shared ones give the same
+// access as `&T`, plus `&mut T` after joining, which is covered by `SendAccess`
+unsafe impl<T, B, const LEVEL: usize> Send for Ref<T, B, LEVEL>
+where
+    T: ?Sized,
+    B: IsBrand + Send,
+    Access<{ LEVEL == 0 }>: SendAccess<T>,
+{
+}
+
+// SAFETY: `&Ref` only gives out `&T`
+unsafe impl<T, B, const LEVEL: usize> Sync for Ref<T, B, LEVEL>
+where
+    T: ?Sized + Sync,
+    B: IsBrand,
+{
+}
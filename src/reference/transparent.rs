@@ -0,0 +1,51 @@
+//! Casting references to transparent wrappers and their contents
+
+use bytemuck::TransparentWrapper;
+
+use super::Ref;
+use crate::brand::IsBrand;
+
+impl<T, B, const LEVEL: usize> Ref<T, B, LEVEL>
+where
+    B: IsBrand,
+{
+    /// View the value of a transparent wrapper as the value it wraps, keeping the brand and level.
+    /// The reference can be turned back with [`.wrap()`](Ref::wrap) to join or reconstruct it.
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use nolife::*;
+    /// use bytemuck::TransparentWrapper;
+    ///
+    /// #[repr(transparent)]
+    /// struct Meters(f32);
+    /// // SAFETY: `Meters` is a `repr(transparent)` wrapper around `f32`
+    /// unsafe impl TransparentWrapper<f32> for Meters {}
+    ///
+    /// let (husk, reference) = borrow!(heap!(Meters(1.5)));
+    /// let mut raw: RefMut<f32, _> = reference.peel();
+    /// *raw *= 2.0;
+    /// let owned = raw.wrap::<Meters>().reconstruct(husk);
+    /// assert_eq!(owned.into_inner().0, 3.0);
+    /// ```
+    #[must_use]
+    pub fn peel<Inner>(self) -> Ref<Inner, B, LEVEL>
+    where
+        T: TransparentWrapper<Inner>,
+    {
+        // SAFETY: `TransparentWrapper` guarantees that `T` has the same layout and validity as
+        // `Inner`
+        unsafe { self.cast_unchecked() }
+    }
+
+    /// View the value as the transparent wrapper `W`, keeping the brand and level. Reverses
+    /// [`.peel()`](Ref::peel).
+    #[must_use]
+    pub fn wrap<W>(self) -> Ref<W, B, LEVEL>
+    where
+        W: TransparentWrapper<T>,
+    {
+        // SAFETY: `TransparentWrapper` guarantees that `W` has the same layout and validity as `T`
+        unsafe { self.cast_unchecked() }
+    }
+}
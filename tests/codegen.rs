@@ -0,0 +1,44 @@
+//! Zero-overhead checks for a full borrow round trip.
+//!
+//! The `#[no_mangle]` functions below should compile to the same code. Compare them with
+//! `cargo rustc --release --test codegen -- --emit asm`. The tests check the observable part:
+//! references and husks add nothing to the pointer, and the allocation is never moved.
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+use std::hint::black_box;
+
+use nolife::{borrow, Owned};
+
+#[no_mangle]
+pub fn round_trip_nolife(boxed: Box<u64>) -> Box<u64> {
+    let (husk, mut reference) = borrow!(Owned::from_box(boxed));
+    *reference += 1;
+    let [left, right] = reference.split();
+    left.join(right).reconstruct(husk).into_box()
+}
+
+#[no_mangle]
+pub fn round_trip_box(mut boxed: Box<u64>) -> Box<u64> {
+    *boxed += 1;
+    boxed
+}
+
+#[test]
+fn round_trip_keeps_allocation() {
+    let boxed = Box::new(1);
+    let ptr: *const u64 = &*boxed;
+    let boxed = black_box(round_trip_nolife(black_box(boxed)));
+    assert_eq!(ptr, &*boxed);
+    assert_eq!(*boxed, *round_trip_box(Box::new(1)));
+}
+
+#[test]
+fn references_are_pointers() {
+    let (husk, reference) = borrow!(Owned::from_box(Box::new(0_u64)));
+    assert_eq!(size_of_val(&reference), size_of::<*mut u64>());
+    assert_eq!(size_of_val(&husk), 0);
+    let [left, right] = reference.split();
+    assert_eq!(size_of_val(&left), size_of::<*mut u64>());
+    let _owned = left.join(right).reconstruct(husk);
+}
@@ -0,0 +1,69 @@
+//! Ownership kind implemented outside of the crate, using only the public API
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+use std::{
+    ptr::NonNull,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use nolife::{borrow, MoveOut, Owned, OwnershipKind};
+
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+
+/// Heap allocation which keeps track of the number of live values
+struct Tracked;
+
+fn tracked<T>(val: T) -> Owned<T, Tracked> {
+    LIVE.fetch_add(1, Ordering::Relaxed);
+    // SAFETY: the value is owned by a fresh `Box`
+    unsafe { Owned::from_inner(Box::new(val)) }
+}
+
+// SAFETY: the pointer owns the allocation until it's passed back to `Box::from_raw`, and a dropped
+// husk leaks the value
+unsafe impl<T> OwnershipKind<T> for Tracked {
+    type Pointee = T;
+    type Husk = ();
+    type Inner = Box<T>;
+
+    fn split(val: Self::Inner) -> (Self::Husk, NonNull<Self::Pointee>) {
+        ((), NonNull::from(Box::leak(val)))
+    }
+
+    unsafe fn join(_husk: Self::Husk, ptr: NonNull<Self::Pointee>) -> Self::Inner {
+        // SAFETY: pointer was obtained from `Box::leak` in `.split()` and we are the only owner
+        unsafe { Box::from_raw(ptr.as_ptr()) }
+    }
+
+    fn drop_inner(val: Self::Inner) {
+        LIVE.fetch_sub(1, Ordering::Relaxed);
+        drop(val);
+    }
+}
+
+impl<T> MoveOut<T> for Tracked {
+    fn move_out(val: Self::Inner) -> T {
+        LIVE.fetch_sub(1, Ordering::Relaxed);
+        *val
+    }
+}
+
+#[test]
+fn custom_kind() {
+    let owned = tracked(vec![1, 2]);
+    let other = tracked(0);
+    assert_eq!(LIVE.load(Ordering::Relaxed), 2);
+
+    let (husk, mut reference) = borrow!(owned);
+    reference.push(3);
+    let [ref1, ref2] = reference.split();
+    assert_eq!(ref1.len(), ref2.len());
+    let owned = ref1.join(ref2).reconstruct(husk);
+    assert_eq!(LIVE.load(Ordering::Relaxed), 2);
+
+    drop(other);
+    assert_eq!(LIVE.load(Ordering::Relaxed), 1);
+    assert_eq!(owned.into_inner(), [1, 2, 3]);
+    assert_eq!(LIVE.load(Ordering::Relaxed), 0);
+}
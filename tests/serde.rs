@@ -0,0 +1,52 @@
+//! Round trips of values containing owned values through JSON
+#![cfg(feature = "serde")]
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+use nolife::{borrow, Heap, Owned};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+struct Document {
+    title: Owned<String, Heap>,
+    sections: Vec<Owned<Section, Heap>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Section {
+    heading: String,
+    body: Owned<str, Heap>,
+}
+
+const JSON: &str = r#"{"title":"notes","sections":[{"heading":"a","body":"first"},{"heading":"b","body":"second"}]}"#;
+
+#[test]
+fn round_trip() {
+    let document: Document = serde_json::from_str(JSON).unwrap();
+    assert_eq!(document.title.into_inner(), "notes");
+    let bodies: Vec<_> = document
+        .sections
+        .into_iter()
+        .map(|section| section.into_inner().body.into_box())
+        .collect();
+    assert_eq!(bodies, [Box::<str>::from("first"), Box::from("second")]);
+
+    let document: Document = serde_json::from_str(JSON).unwrap();
+    assert_eq!(serde_json::to_string(&document).unwrap(), JSON);
+}
+
+#[test]
+fn serialize_borrowed() {
+    let document: Document = serde_json::from_str(JSON).unwrap();
+    let (husk, mut section) = borrow!(document.sections.into_iter().next().unwrap());
+    assert_eq!(
+        serde_json::to_string(&section).unwrap(),
+        r#"{"heading":"a","body":"first"}"#
+    );
+    section.heading.push('!');
+    let section = section.reconstruct(husk);
+    assert_eq!(
+        serde_json::to_string(&section).unwrap(),
+        r#"{"heading":"a!","body":"first"}"#
+    );
+}
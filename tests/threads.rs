@@ -0,0 +1,85 @@
+//! Fanning shared references out to threads and joining them back
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+use std::thread;
+
+use nolife::{borrow, heap, Ref};
+
+struct Table {
+    rows: Vec<u64>,
+}
+
+impl Table {
+    fn new(len: u64) -> Self {
+        Self {
+            rows: (0..len).collect(),
+        }
+    }
+
+    fn sum(&self, part: usize, parts: usize) -> u64 {
+        self.rows
+            .chunks(self.rows.len().div_ceil(parts))
+            .nth(part)
+            .unwrap()
+            .iter()
+            .sum()
+    }
+}
+
+#[test]
+fn scoped_threads() {
+    let (husk, mut table) = borrow!(heap!(Table::new(1000)));
+    table.rows.push(1000);
+
+    let refs = table.split_n::<4>();
+    let (sums, refs): (Vec<_>, Vec<_>) = thread::scope(|scope| {
+        let workers: Vec<_> = refs
+            .into_iter()
+            .enumerate()
+            .map(|(part, table)| scope.spawn(move || (table.sum(part, 4), table)))
+            .collect();
+        workers
+            .into_iter()
+            .map(|worker| worker.join().unwrap())
+            .unzip()
+    });
+    assert_eq!(sums.iter().sum::<u64>(), 500_500);
+
+    let refs: [_; 4] = refs.try_into().ok().unwrap();
+    let mut table = Ref::join_n(refs);
+    table.rows.clear();
+    assert!(table.reconstruct(husk).into_inner().rows.is_empty());
+}
+
+#[test]
+fn scoped_threads_borrowing_refs() {
+    let (husk, table) = borrow!(heap!(Table::new(1001)));
+    let refs = table.split_n::<4>();
+    // Threads can also borrow the references, which stay in place for joining
+    let sums: Vec<_> = thread::scope(|scope| {
+        let workers: Vec<_> = refs
+            .iter()
+            .enumerate()
+            .map(|(part, table)| scope.spawn(move || table.sum(part, 4)))
+            .collect();
+        workers
+            .into_iter()
+            .map(|worker| worker.join().unwrap())
+            .collect()
+    });
+    assert_eq!(sums.iter().sum::<u64>(), 500_500);
+    Ref::join_n(refs).reconstruct(husk);
+}
+
+#[test]
+fn rayon_join() {
+    let (husk, table) = borrow!(heap!(Table::new(1001)));
+    let [left, right] = table.split();
+    let ((left_sum, left), (right_sum, right)) = rayon::join(
+        move || (left.sum(0, 2), left),
+        move || (right.sum(1, 2), right),
+    );
+    assert_eq!(left_sum + right_sum, 500_500);
+    left.join(right).reconstruct(husk);
+}
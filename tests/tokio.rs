@@ -0,0 +1,62 @@
+//! Async IO through references to tokio IO objects
+#![cfg(feature = "tokio")]
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+use std::{future::Future, io::IoSlice};
+
+use nolife::{borrow, heap};
+use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .build()
+        .expect("runtime should start")
+        .block_on(future)
+}
+
+#[test]
+fn duplex_through_references() {
+    block_on(async {
+        let (client, server) = duplex(64);
+        let (client_husk, mut client) = borrow!(heap!(client));
+        let (server_husk, mut server) = borrow!(heap!(server));
+
+        client.write_all(b"ping").await.unwrap();
+        let written = client
+            .write_vectored(&[IoSlice::new(b", "), IoSlice::new(b"pong")])
+            .await
+            .unwrap();
+        assert!(written > 0);
+        client.flush().await.unwrap();
+        client.shutdown().await.unwrap();
+
+        let mut received = Vec::new();
+        server.read_to_end(&mut received).await.unwrap();
+        assert!(received.starts_with(b"ping, "));
+
+        drop(client.reconstruct(client_husk));
+        drop(server.reconstruct(server_husk));
+    });
+}
+
+#[test]
+fn copy_between_references() {
+    block_on(async {
+        let (client, server) = duplex(64);
+        let (client_husk, mut client) = borrow!(heap!(client));
+        let (server_husk, mut server) = borrow!(heap!(server));
+        let (sink_husk, mut sink) = borrow!(heap!(Vec::new()));
+
+        client.write_all(b"hello").await.unwrap();
+        client.shutdown().await.unwrap();
+        let copied = tokio::io::copy(&mut server.reborrow(), &mut sink.reborrow())
+            .await
+            .unwrap();
+        assert_eq!(copied, 5);
+        assert_eq!(sink.reconstruct(sink_husk).into_inner(), b"hello");
+
+        drop(client.reconstruct(client_husk));
+        drop(server.reconstruct(server_husk));
+    });
+}
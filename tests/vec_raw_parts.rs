@@ -0,0 +1,66 @@
+//! Round trip of `Vec` raw parts through `VecKind`. Run under miri to check for undefined behavior:
+//! ```sh
+//! cargo +nightly miri test --no-default-features --test vec_raw_parts
+//! ```
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use nolife::{borrow, Owned};
+
+/// Split the vector, write through the reference, and reconstruct it
+fn round_trip<T>(vec: Vec<T>, update: impl FnOnce(&mut [T])) -> Vec<T> {
+    let (husk, mut reference) = borrow!(Owned::wrap_vec(vec));
+    update(&mut reference);
+    reference.reconstruct(husk).into_inner()
+}
+
+#[test]
+fn spare_capacity() {
+    let mut vec = Vec::with_capacity(8);
+    vec.extend([3, 1, 2]);
+    let ptr = vec.as_ptr();
+    let mut vec = round_trip(vec, <[i32]>::sort_unstable);
+    assert_eq!(vec.as_ptr(), ptr);
+    assert_eq!(vec.capacity(), 8);
+    // Pushing into the spare capacity must not reallocate
+    vec.extend([4, 5, 6, 7, 8]);
+    assert_eq!(vec.as_ptr(), ptr);
+    assert_eq!(vec, [1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn empty() {
+    let mut vec = round_trip(Vec::<String>::new(), |slice| assert!(slice.is_empty()));
+    assert_eq!(vec.capacity(), 0);
+    vec.push(String::from("grown"));
+    assert_eq!(vec, ["grown"]);
+
+    let mut vec = round_trip(Vec::<String>::with_capacity(4), |slice| {
+        assert!(slice.is_empty());
+    });
+    assert_eq!(vec.capacity(), 4);
+    vec.push(String::from("spare"));
+    assert_eq!(vec, ["spare"]);
+}
+
+#[test]
+fn zero_sized() {
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    struct Token;
+    impl Drop for Token {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let vec: Vec<Token> = (0..3).map(|_| Token).collect();
+    let mut vec = round_trip(vec, |slice| assert_eq!(slice.len(), 3));
+    assert_eq!(DROPS.load(Ordering::Relaxed), 0);
+    vec.push(Token);
+    assert_eq!(vec.len(), 4);
+    drop(vec);
+    assert_eq!(DROPS.load(Ordering::Relaxed), 4);
+}